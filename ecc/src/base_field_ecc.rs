@@ -4,6 +4,7 @@ use crate::integer::rns::{Integer, Rns};
 use crate::{halo2, maingate};
 use halo2::arithmetic::CurveAffine;
 use halo2::circuit::Layouter;
+use halo2::halo2curves::ff::Field;
 use halo2::plonk::Error;
 use integer::halo2::circuit::Value;
 use integer::maingate::{MainGateInstructions, RegionCtx};
@@ -78,21 +79,36 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Point { x, y }
     }
 
+    /// Returns emulated EC constant $a$
+    fn parameter_a(&self) -> Integer<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(C::a(), self.rns())
+    }
+
+    /// Returns `true` if emulated EC constant $a$ is not zero. Most of the curves in
+    /// use have `a = 0` so the terms of $a$ are skipped then
+    fn has_parameter_a() -> bool {
+        !bool::from(C::a().is_zero())
+    }
+
     /// Returns emulated EC constant $b$
     fn parameter_b(&self) -> Integer<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         Integer::from_fe(C::b(), self.rns())
     }
 
     /// Auxilary point for optimized multiplication algorithm
+    /// Returns `Error::Synthesis` if the aux generator or the auxiliary point
+    /// for `(window_size, number_of_pairs)` is not assigned yet
     fn get_mul_aux(
         &self,
         window_size: usize,
         number_of_pairs: usize,
     ) -> Result<MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // Aux generator must be assigned with `assign_aux_generator`
         let to_add = match self.aux_generator.clone() {
             Some((assigned, _)) => Ok(assigned),
             None => Err(Error::Synthesis),
         }?;
+        // `(window_size, number_of_pairs)` must be assigned with `assign_aux`
         let to_sub = match self.aux_registry.get(&(window_size, number_of_pairs)) {
             Some(aux) => Ok(aux.clone()),
             None => Err(Error::Synthesis),
@@ -197,6 +213,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     }
 
     /// Constraints to ensure `AssignedPoint` is on curve
+    /// `y^2 = x^3 + a * x + b`
     pub fn assert_is_on_curve(
         &self,
         ctx: &mut RegionCtx<'_, C::Scalar>,
@@ -208,7 +225,13 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         let x_square = &integer_chip.square(ctx, point.x())?;
         let x_cube = &integer_chip.mul(ctx, point.x(), x_square)?;
         let x_cube_b = &integer_chip.add_constant(ctx, x_cube, &self.parameter_b())?;
-        integer_chip.assert_equal(ctx, x_cube_b, y_square)?;
+        let rhs = &if Self::has_parameter_a() {
+            let a_x = &integer_chip.mul_constant(ctx, point.x(), &self.parameter_a())?;
+            integer_chip.add(ctx, x_cube_b, a_x)?
+        } else {
+            x_cube_b.clone()
+        };
+        integer_chip.assert_equal(ctx, rhs, y_square)?;
         Ok(())
     }
 
@@ -283,6 +306,11 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     }
 
     /// Doubles an `AssignedPoint`
+    ///
+    /// The point must be on the curve, which `assign_point` constrains, and
+    /// must not be the identity, which an `AssignedPoint` never is. A point
+    /// that is not on the curve leads to an erroneous result rather than an
+    /// unsatisfiable circuit.
     pub fn double(
         &self,
         ctx: &mut RegionCtx<'_, C::Scalar>,
@@ -349,8 +377,9 @@ mod tests {
     use crate::curves::pasta::{EpAffine as Pallas, EqAffine as Vesta};
     use crate::halo2;
     use crate::integer::rns::Rns;
-    use crate::integer::NUMBER_OF_LOOKUP_LIMBS;
+    use crate::integer::{IntegerInstructions, Range, NUMBER_OF_LOOKUP_LIMBS};
     use crate::maingate;
+    use crate::{EccInstructions, GeneralEccChip};
     use halo2::arithmetic::CurveAffine;
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
     use halo2::halo2curves::{
@@ -714,6 +743,267 @@ mod tests {
         run::<Vesta>();
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulAgainstGeneral<C: CurveAffine> {
+        window_size: usize,
+        aux_generator: C,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccMulAgainstGeneral<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            // general chip also emulates the scalar field
+            let (rns_base, rns_scalar) =
+                GeneralEccChip::<C, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+
+            let main_gate_config = MainGate::<C::Scalar>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<C::Scalar>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(config.ecc_chip_config());
+            let mut general_ecc_chip =
+                GeneralEccChip::<C, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                    config.ecc_chip_config(),
+                );
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    general_ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    general_ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::CurveExt::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let result = base * s;
+
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let s_native = main_gate.assign_value(ctx, Value::known(s))?;
+                    let s_emulated = general_ecc_chip.new_unassigned_scalar(Value::known(s));
+                    let s_emulated = general_ecc_chip.scalar_field_chip().assign_integer(
+                        ctx,
+                        s_emulated,
+                        Range::Remainder,
+                    )?;
+
+                    let offset = ctx.offset();
+                    let result_1 = ecc_chip.mul(ctx, &base, &s_native, self.window_size)?;
+                    let base_field_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let result_2 =
+                        general_ecc_chip.mul(ctx, &base, &s_emulated, self.window_size)?;
+                    let general_rows = ctx.offset() - offset;
+
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+                    assert!(base_field_rows < general_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_mul_against_general_circuit() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            for window_size in 1..4 {
+                let aux_generator = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+
+                let circuit = TestEccMulAgainstGeneral {
+                    aux_generator,
+                    window_size,
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
+    fn mul_with_instructions<C: CurveAffine, E: EccInstructions<C, C::Scalar>>(
+        ecc_chip: &E,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        base: C,
+        scalar: &E::AssignedScalar,
+        expected: C,
+        window_size: usize,
+    ) -> Result<(), Error> {
+        let base = ecc_chip.assign_point(ctx, Value::known(base))?;
+        let expected = ecc_chip.assign_point(ctx, Value::known(expected))?;
+        let result = ecc_chip.mul(ctx, &base, scalar, window_size)?;
+        ecc_chip.assert_is_on_curve(ctx, &result)?;
+        ecc_chip.assert_equal(ctx, &expected, &result)
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccInstructionsMul<C: CurveAffine> {
+        window_size: usize,
+        aux_generator: C,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccInstructionsMul<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestEccMulAgainstGeneral::<C>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(config.ecc_chip_config());
+            let mut general_ecc_chip =
+                GeneralEccChip::<C, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                    config.ecc_chip_config(),
+                );
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    general_ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    general_ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::CurveExt::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let result = (base * s).to_affine();
+                    let base = base.to_affine();
+
+                    let s_native = main_gate.assign_value(ctx, Value::known(s))?;
+                    mul_with_instructions(
+                        &ecc_chip,
+                        ctx,
+                        base,
+                        &s_native,
+                        result,
+                        self.window_size,
+                    )?;
+
+                    let s_emulated = general_ecc_chip.new_unassigned_scalar(Value::known(s));
+                    let s_emulated = general_ecc_chip.scalar_field_chip().assign_integer(
+                        ctx,
+                        s_emulated,
+                        Range::Remainder,
+                    )?;
+                    mul_with_instructions(
+                        &general_ecc_chip,
+                        ctx,
+                        base,
+                        &s_emulated,
+                        result,
+                        self.window_size,
+                    )?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_instructions_mul_circuit() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            for window_size in 1..4 {
+                let aux_generator = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+
+                let circuit = TestEccInstructionsMul {
+                    aux_generator,
+                    window_size,
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccBatchMul<C: CurveAffine> {
         window_size: usize,
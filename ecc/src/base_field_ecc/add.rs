@@ -51,9 +51,14 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let integer_chip = self.integer_chip();
 
-        // lambda = (3 * a_x^2) / 2 * a_y
+        // lambda = (3 * a_x^2 + a) / 2 * a_y
         let x_0_square = &integer_chip.square(ctx, &point.x)?;
         let numerator = &integer_chip.mul3(ctx, x_0_square)?;
+        let numerator = &if Self::has_parameter_a() {
+            integer_chip.add_constant(ctx, numerator, &self.parameter_a())?
+        } else {
+            numerator.clone()
+        };
         let denominator = &integer_chip.mul2(ctx, &point.y)?;
         let lambda = &integer_chip.div_incomplete(ctx, numerator, denominator)?;
 
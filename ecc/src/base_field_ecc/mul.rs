@@ -1,6 +1,6 @@
 use super::{AssignedPoint, BaseFieldEccChip};
 use crate::maingate::{AssignedCondition, AssignedValue, MainGateInstructions};
-use crate::{halo2, Selector, Table, Windowed};
+use crate::{check_window_size, halo2, windows_from_bits, Selector, Table, Windowed};
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::{Field, PrimeField};
 use halo2::plonk::Error;
@@ -9,8 +9,10 @@ use integer::maingate::RegionCtx;
 impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
-    /// Pads scalar up to the next window_size mul
-    fn pad(
+    /// Pads little endian decomposition of a scalar with zeros up to the next
+    /// window_size mul and reverses it into big endian order that
+    /// `windows_from_bits` expects
+    pub fn pad(
         &self,
         ctx: &mut RegionCtx<'_, C::Scalar>,
         bits: &mut Vec<AssignedCondition<C::Scalar>>,
@@ -30,21 +32,10 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(())
     }
 
-    /// Splits the bit representation of a scalar into windows
+    /// Splits the bit representation of a scalar into windows. See
+    /// `windows_from_bits`.
     fn window(bits: Vec<AssignedCondition<C::Scalar>>, window_size: usize) -> Windowed<C::Scalar> {
-        assert_eq!(bits.len() % window_size, 0);
-        let number_of_windows = bits.len() / window_size;
-        Windowed(
-            (0..number_of_windows)
-                .map(|i| {
-                    let mut selector: Vec<AssignedCondition<C::Scalar>> = (0..window_size)
-                        .map(|j| bits[i * window_size + j].clone())
-                        .collect();
-                    selector.reverse();
-                    Selector(selector)
-                })
-                .collect(),
-        )
+        windows_from_bits(bits, window_size)
     }
 
     /// Constructs table for efficient multiplication algorithm
@@ -57,6 +48,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         point: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         window_size: usize,
     ) -> Result<Table<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
         let table_size = 1 << window_size;
         let mut table = vec![aux.clone()];
         for i in 0..(table_size - 1) {
@@ -74,6 +66,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let number_of_points = table.0.len();
         let number_of_selectors = selector.0.len();
+        check_window_size(number_of_selectors)?;
         assert_eq!(number_of_points, 1 << number_of_selectors);
 
         let mut reducer = table.0.clone();
@@ -89,6 +82,8 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
 
     /// Scalar multiplication of a point in the EC
     /// Performed with the sliding-window algorithm
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
     pub fn mul(
         &self,
         ctx: &mut RegionCtx<'_, C::Scalar>,
@@ -96,7 +91,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         scalar: &AssignedValue<C::Scalar>,
         window_size: usize,
     ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        assert!(window_size > 0);
+        check_window_size(window_size)?;
         let aux = self.get_mul_aux(window_size, 1)?;
 
         let main_gate = self.main_gate();
@@ -121,12 +116,29 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         self.add(ctx, &acc, &aux.to_sub)
     }
 
+    /// Returns `[aux, 2 * aux, ..., 2^(number_of_pairs - 1) * aux]` that the
+    /// tables of a batch are built on
+    fn binary_aux(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        aux: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        number_of_pairs: usize,
+    ) -> Result<Vec<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error> {
+        let mut binary_aux = vec![aux.clone()];
+        for i in 1..number_of_pairs {
+            binary_aux.push(self.double(ctx, &binary_aux[i - 1])?);
+        }
+        Ok(binary_aux)
+    }
+
     /// Computes multi-product
     ///
     /// Given a vector of point, scalar pairs
     /// `[(P_0, e_0), (P_1, e_1), ..., (P_k, e_k)]`
     /// Returns
     /// ` P_0 * e_0 + P_1 * e_1 + ...+ P_k * e_k`
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
     #[allow(clippy::type_complexity)]
     pub fn mul_batch_1d_horizontal(
         &self,
@@ -137,8 +149,8 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         )>,
         window_size: usize,
     ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        assert!(window_size > 0);
         assert!(!pairs.is_empty());
+        check_window_size(window_size)?;
         let aux = self.get_mul_aux(window_size, pairs.len())?;
 
         let main_gate = self.main_gate();
@@ -158,16 +170,12 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
             .collect();
         let number_of_windows = windowed_scalars[0].0.len();
 
-        let mut binary_aux = aux.to_add.clone();
+        let binary_aux = self.binary_aux(ctx, &aux.to_add, pairs.len())?;
         let tables: Vec<Table<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = pairs
             .iter()
-            .enumerate()
-            .map(|(i, (point, _))| {
-                let table = self.make_incremental_table(ctx, &binary_aux, point, window_size);
-                if i != pairs.len() - 1 {
-                    binary_aux = self.double(ctx, &binary_aux)?;
-                }
-                table
+            .zip(binary_aux.iter())
+            .map(|((point, _), binary_aux)| {
+                self.make_incremental_table(ctx, binary_aux, point, window_size)
             })
             .collect::<Result<_, Error>>()?;
 
@@ -1,20 +1,34 @@
-use super::{make_mul_aux, AssignedPoint, EccConfig, MulAux, Point};
+use super::{
+    aux_generator_from_tag, check_window_size, make_mul_aux, make_mul_aux_booth,
+    make_mul_aux_bounded, make_mul_aux_naf, mul_aux_cancels, AssignedPoint, EccConfig, MulAux,
+    Point, RowLimitExceeded, MAP_TO_CURVE_ATTEMPTS,
+};
 use crate::halo2;
-use crate::integer::rns::{Integer, Rns};
-use crate::integer::{IntegerChip, IntegerInstructions, Range, UnassignedInteger};
+use crate::integer::rns::{Common, Integer, Rns};
+use crate::integer::{AssignedInteger, IntegerChip, IntegerInstructions, Range, UnassignedInteger};
 use crate::maingate;
 use halo2::arithmetic::CurveAffine;
 use halo2::circuit::{Layouter, Value};
-use halo2::halo2curves::ff::PrimeField;
+use halo2::halo2curves::ff::{Field, PrimeField};
+use halo2::halo2curves::group::prime::PrimeCurveAffine;
 use halo2::plonk::Error;
 use integer::maingate::RegionCtx;
-use maingate::{AssignedCondition, MainGate};
+use maingate::{AssignedCondition, AssignedValue, MainGate};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
 mod add;
+mod booth;
+mod complete;
+mod estimate;
+mod fixed;
+mod glv;
+#[cfg(feature = "dynamic-lookup")]
+mod lookup;
 mod mul;
 
+pub use glv::HasEndomorphism;
+
 /// Constaints elliptic curve operations such as assigment, addition and
 /// multiplication
 #[derive(Clone, Debug)]
@@ -38,6 +52,18 @@ pub struct GeneralEccChip<
     /// n_pairs) pairs
     aux_registry:
         BTreeMap<(usize, usize), AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Auxiliary points for signed digit multiplication for each window_size
+    aux_naf_registry:
+        BTreeMap<usize, AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Auxiliary points for bounded scalar multiplication for each
+    /// (window_size, max_bits) pairs
+    aux_bounded_registry:
+        BTreeMap<(usize, usize), AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Auxiliary points for Booth recoded multiplication for each window_size
+    aux_booth_registry:
+        BTreeMap<usize, AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Offset of the region that batch multiplication must not exceed
+    max_rows: Option<usize>,
 }
 
 impl<
@@ -66,6 +92,37 @@ impl<
             scalar_field_chip: IntegerChip::new(integer_config, Rc::new(rns_scalar_field)),
             aux_generator: None,
             aux_registry: BTreeMap::new(),
+            aux_naf_registry: BTreeMap::new(),
+            aux_bounded_registry: BTreeMap::new(),
+            aux_booth_registry: BTreeMap::new(),
+            max_rows: None,
+        }
+    }
+
+    /// Sets the offset of the region that batch multiplication must not
+    /// exceed. Offset is checked after each table and each window of a batch
+    /// is assigned, so that an oversized batch fails with
+    /// [`RowLimitExceeded`] naming the operation and by how many rows it went
+    /// past the limit instead of running out of rows later in the layouter.
+    /// `None` removes the limit.
+    pub fn set_max_rows(&mut self, max_rows: Option<usize>) {
+        self.max_rows = max_rows;
+    }
+
+    /// Returns [`RowLimitExceeded`] for the `operation` if the region went
+    /// past the limit set with `set_max_rows`
+    pub(crate) fn check_max_rows(
+        &self,
+        ctx: &RegionCtx<'_, N>,
+        operation: &'static str,
+    ) -> Result<(), Error> {
+        match self.max_rows {
+            Some(max_rows) if ctx.offset() > max_rows => Err(RowLimitExceeded {
+                operation,
+                overflow: ctx.offset() - max_rows,
+            }
+            .into()),
+            _ => Ok(()),
         }
     }
 
@@ -131,22 +188,36 @@ impl<
         Point { x, y }
     }
 
+    /// Returns emulated EC constant $a$
+    fn parameter_a(&self) -> Integer<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(Emulated::a(), self.rns_base())
+    }
+
+    /// Returns `true` if the emulated EC constant $a$ is not zero. Most of
+    /// the curves in use have `a = 0` so the terms of $a$ are skipped then
+    fn has_parameter_a() -> bool {
+        !bool::from(Emulated::a().is_zero())
+    }
+
     /// Returns emulated EC constant $b$
     fn parameter_b(&self) -> Integer<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         Integer::from_fe(Emulated::b(), self.rns_base())
     }
 
     /// Auxilary point for optimized multiplication algorithm
+    /// Returns `Error::Synthesis` if the aux generator or the auxiliary point
+    /// for `(window_size, number_of_pairs)` is not assigned yet
     fn get_mul_aux(
         &self,
         window_size: usize,
         number_of_pairs: usize,
     ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        // Gets chips' aux generator
+        // Aux generator must be assigned with `assign_aux_generator`
         let to_add = match self.aux_generator.clone() {
             Some((assigned, _)) => Ok(assigned),
             None => Err(Error::Synthesis),
         }?;
+        // `(window_size, number_of_pairs)` must be assigned with `assign_aux`
         let to_sub = match self.aux_registry.get(&(window_size, number_of_pairs)) {
             Some(aux) => Ok(aux.clone()),
             None => Err(Error::Synthesis),
@@ -155,6 +226,56 @@ impl<
         // see https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
         Ok(MulAux::new(to_add, to_sub))
     }
+
+    /// Auxilary point for signed digit multiplication algorithm
+    fn get_mul_aux_naf(
+        &self,
+        window_size: usize,
+    ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        let to_sub = match self.aux_naf_registry.get(&window_size) {
+            Some(aux) => Ok(aux.clone()),
+            None => Err(Error::Synthesis),
+        }?;
+        Ok(MulAux::new(to_add, to_sub))
+    }
+
+    /// Auxilary point for Booth recoded multiplication algorithm
+    fn get_mul_aux_booth(
+        &self,
+        window_size: usize,
+    ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        let to_sub = match self.aux_booth_registry.get(&window_size) {
+            Some(aux) => Ok(aux.clone()),
+            None => Err(Error::Synthesis),
+        }?;
+        Ok(MulAux::new(to_add, to_sub))
+    }
+
+    /// Auxilary point for multiplication with scalars bounded to `max_bits`
+    fn get_mul_aux_bounded(
+        &self,
+        window_size: usize,
+        max_bits: usize,
+    ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        // `(window_size, max_bits)` must be assigned with `assign_aux_bounded`
+        let to_sub = match self.aux_bounded_registry.get(&(window_size, max_bits)) {
+            Some(aux) => Ok(aux.clone()),
+            None => Err(Error::Synthesis),
+        }?;
+        Ok(MulAux::new(to_add, to_sub))
+    }
 }
 
 impl<
@@ -186,6 +307,74 @@ impl<
         Ok(())
     }
 
+    /// Expose `AssignedPoint` as Public Input in compressed form. Limbs of
+    /// the reduced `x` coordinate are exposed starting from `offset` and are
+    /// followed by the parity of `y`, so `NUMBER_OF_LIMBS + 1` instance rows
+    /// are used rather than `2 * NUMBER_OF_LIMBS`. `x` is constrained to be
+    /// in field so that a point has a single encoding. Values are laid out
+    /// as `Point::public_compressed` returns them and can be read back with
+    /// `from_compressed_instance`.
+    pub fn expose_compressed(
+        &self,
+        mut layouter: impl Layouter<N>,
+        point: AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        use integer::maingate::MainGateInstructions;
+        let main_gate = self.main_gate();
+        let integer_chip = self.base_field_chip();
+
+        let (x, sign) = layouter.assign_region(
+            || "compress",
+            |region| {
+                let offset = 0;
+                let ctx = &mut RegionCtx::new(region, offset);
+                let x = integer_chip.reduce(ctx, point.x())?;
+                integer_chip.assert_in_field(ctx, &x)?;
+                let sign = integer_chip.sign(ctx, point.y())?;
+                Ok((x, sign))
+            },
+        )?;
+
+        let mut offset = offset;
+        for limb in x.limbs().iter() {
+            main_gate.expose_public(layouter.namespace(|| "x coords"), limb.into(), offset)?;
+            offset += 1;
+        }
+        main_gate.expose_public(layouter.namespace(|| "y sign"), sign, offset)
+    }
+
+    /// Recovers a point exposed with `expose_compressed` starting from the
+    /// row `offset` of the instance column. Limbs of `x` are range checked
+    /// and `x` is constrained to be in field. Proof is unsatisfiable if the
+    /// sign is not a bit or if `x` is not an abscissa of a point on the curve.
+    pub fn from_compressed_instance(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        offset: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        use integer::maingate::MainGateInstructions;
+        let main_gate = self.main_gate();
+        let integer_chip = self.base_field_chip();
+
+        let limbs = (offset..offset + NUMBER_OF_LIMBS)
+            .map(|row| main_gate.assign_from_instance(ctx, row))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let sign = main_gate.assign_from_instance(ctx, offset + NUMBER_OF_LIMBS)?;
+        main_gate.assert_bit(ctx, &sign)?;
+
+        // `x` is assigned with range checks and bound to the instance limbs
+        let x: Value<Vec<N>> = limbs.iter().map(|limb| limb.value().copied()).collect();
+        let x = x.map(|limbs| Integer::from_limbs(&limbs.try_into().unwrap(), self.rns_base()));
+        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+        for (limb, instance_limb) in x.limbs().iter().zip(limbs.iter()) {
+            ctx.constrain_equal(limb.as_ref().cell(), instance_limb.cell())?;
+        }
+        integer_chip.assert_in_field(ctx, &x)?;
+
+        self.from_compressed(ctx, &x, &sign)
+    }
+
     /// Takes `Point` and assign its coordiantes as constant
     /// Returned as `AssignedPoint`
     pub fn assign_constant(
@@ -204,37 +393,143 @@ impl<
     }
 
     /// Takes `Point` of the EC and returns it as `AssignedPoint`
+    /// Assigned point is constrained to be on the curve
     pub fn assign_point(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         point: Value<Emulated>,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let integer_chip = self.base_field_chip();
-
-        let point = point.map(|point| self.to_rns_point(point));
         let (x, y) = point
-            .map(|point| (point.x().clone(), point.y().clone()))
+            .map(|point| {
+                // disallow point of infinity
+                let coords = point.coordinates().unwrap();
+                (*coords.x(), *coords.y())
+            })
             .unzip();
 
-        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
-        let y = integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
-
-        let point = AssignedPoint::new(x, y);
+        let point = self.assign_point_unchecked(ctx, x, y)?;
         self.assert_is_on_curve(ctx, &point)?;
         Ok(point)
     }
 
+    /// Assigns coordinates as `AssignedPoint` without constraining them to be
+    /// on the curve. Coordinates are only range checked. Use it only if the
+    /// point is known to be on the curve by other means, for example if it is
+    /// going to be constrained equal to a checked point, otherwise prefer
+    /// `assign_point`.
+    ///
+    /// Each coordinate takes a row for the range check of each limb and a row
+    /// composing its native value, `2 * (NUMBER_OF_LIMBS + 1)` rows for the
+    /// point. Every such row composes a single value with the main gate, so
+    /// limb range checks of `x` and `y` cannot be interleaved to share rows
+    /// and there is no assignment of a point with consolidated range checks.
+    pub fn assign_point_unchecked(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: Value<Emulated::Base>,
+        y: Value<Emulated::Base>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+
+        let x = integer_chip.assign_integer(ctx, self.new_unassigned_base(x), Range::Remainder)?;
+        let y = integer_chip.assign_integer(ctx, self.new_unassigned_base(y), Range::Remainder)?;
+
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Reads back the witness of an `AssignedPoint` as a point of the emulated
+    /// curve. Returns `None` if the witness is unknown, if the native value of
+    /// a coordinate doesn't agree with its limbs or if the coordinates are not
+    /// on the curve, any of which indicates a bad witness. It adds no
+    /// constraint and is meant for diagnosing `MockProver` runs.
+    pub fn point_value(
+        &self,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Option<Emulated> {
+        let mut consistent = true;
+        for coordinate in [point.x(), point.y()] {
+            coordinate
+                .integer()
+                .zip(coordinate.native().value())
+                .map(|(integer, native)| consistent &= integer.native() == *native);
+        }
+        if consistent {
+            point.debug_value::<Emulated>()
+        } else {
+            None
+        }
+    }
+
     /// Assigns the auxiliary generator point
+    /// Returns `Error::Synthesis` if the point is the identity or not on the
+    /// curve since the auxiliary contribution cannot be cancelled then
     pub fn assign_aux_generator(
         &mut self,
         ctx: &mut RegionCtx<'_, N>,
         aux_generator: Value<Emulated>,
     ) -> Result<(), Error> {
+        aux_generator.error_if_known_and(|point| {
+            bool::from(point.is_identity()) || !bool::from(point.is_on_curve())
+        })?;
         let aux_generator_assigned = self.assign_point(ctx, aux_generator)?;
         self.aux_generator = Some((aux_generator_assigned, aux_generator));
         Ok(())
     }
 
+    /// Assigns the auxiliary generator point as `assign_aux_generator` does
+    /// and multiplication auxiliary points for each pair of (window_size,
+    /// n_pairs) in `configs`
+    pub fn setup_aux_generator(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        aux_generator: Value<Emulated>,
+        configs: &[(usize, usize)],
+    ) -> Result<(), Error> {
+        self.assign_aux_generator(ctx, aux_generator)?;
+        self.setup_aux(ctx, configs)
+    }
+
+    /// Assigns multiplication auxiliary points for each pair of
+    /// (window_size, n_pairs) in `configs` for the assigned aux generator.
+    /// All pairs are validated before any point is assigned, and each
+    /// derived point is checked to cancel the contribution of the aux
+    /// generator in the multiplication algorithm. `mul` and
+    /// `mul_batch_1d_horizontal` then succeed for any configured pair while
+    /// the others keep failing with `Error::Synthesis`.
+    ///
+    /// Returns `Error::Synthesis` if the aux generator is not assigned yet, if
+    /// a window size or a number of pairs is zero, if a window size exceeds
+    /// `MAX_WINDOW_SIZE` or if an auxiliary point doesn't cancel.
+    pub fn setup_aux(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        configs: &[(usize, usize)],
+    ) -> Result<(), Error> {
+        let aux_generator = match self.aux_generator {
+            Some((_, point)) => point,
+            None => return Err(Error::Synthesis),
+        };
+        for (window_size, number_of_pairs) in configs {
+            if *window_size == 0 || *number_of_pairs == 0 {
+                return Err(Error::Synthesis);
+            }
+            check_window_size(*window_size)?;
+        }
+        for (window_size, number_of_pairs) in configs {
+            let (window_size, number_of_pairs) = (*window_size, *number_of_pairs);
+            let aux = aux_generator.map(|point| make_mul_aux(point, window_size, number_of_pairs));
+            aux_generator
+                .zip(aux)
+                .error_if_known_and(|(to_add, to_sub)| {
+                    !mul_aux_cancels(*to_add, *to_sub, window_size, number_of_pairs)
+                })?;
+            let aux = self.assign_point(ctx, aux)?;
+            self.aux_registry
+                .insert((window_size, number_of_pairs), aux);
+        }
+        Ok(())
+    }
+
     /// Assigns multiplication auxiliary point for a pair of (window_size,
     /// n_pairs)
     pub fn assign_aux(
@@ -256,7 +551,153 @@ impl<
         }
     }
 
+    /// Assigns multiplication auxiliary point of a single pair for the
+    /// window_size and derives the ones of batches of up to
+    /// `max_number_of_pairs` pairs from it, so that the size of a batch
+    /// doesn't have to be known in advance. Subtracted auxiliary point scales
+    /// with `2^number_of_pairs - 1` so each further number of pairs costs a
+    /// doubling and an addition here, once, rather than in every batch.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` is not supported or if
+    /// `max_number_of_pairs` is zero
+    pub fn assign_aux_up_to(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+        max_number_of_pairs: usize,
+    ) -> Result<(), Error> {
+        check_window_size(window_size)?;
+        if max_number_of_pairs == 0 {
+            return Err(Error::Synthesis);
+        }
+        self.assign_aux(ctx, window_size, 1)?;
+        let single = self.get_mul_aux(window_size, 1)?.to_sub;
+
+        // `2^n - 1 = 2 * (2^(n-1) - 1) + 1`
+        let mut to_sub = single.clone();
+        for number_of_pairs in 2..=max_number_of_pairs {
+            let doubled = self.double(ctx, &to_sub)?;
+            to_sub = self.add(ctx, &doubled, &single)?;
+            self.aux_registry
+                .insert((window_size, number_of_pairs), to_sub.clone());
+        }
+        Ok(())
+    }
+
+    /// Derives the auxiliary generator from the tag with
+    /// `aux_generator_from_tag`, assigns it and the auxiliary point for a pair
+    /// of (window_size, n_pairs). Auxiliary points assigned before for another
+    /// generator are discarded, so use `assign_aux` for further pairs of
+    /// (window_size, n_pairs).
+    pub fn set_aux_from_tag(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+        number_of_pairs: usize,
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let aux_generator = aux_generator_from_tag::<Emulated>(tag);
+        self.assign_aux_generator(ctx, Value::known(aux_generator))?;
+        self.aux_registry.clear();
+        self.aux_naf_registry.clear();
+        self.aux_bounded_registry.clear();
+        self.aux_booth_registry.clear();
+        self.assign_aux(ctx, window_size, number_of_pairs)
+    }
+
+    /// Assigns auxiliary point for signed digit multiplication for a
+    /// window_size
+    pub fn assign_aux_naf(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+    ) -> Result<(), Error> {
+        match self.aux_generator {
+            Some((_, point)) => {
+                let aux = point.map(|point| make_mul_aux_naf(point, window_size));
+                let aux = self.assign_point(ctx, aux)?;
+                self.aux_naf_registry.insert(window_size, aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Assigns auxiliary point for Booth recoded multiplication for a
+    /// window_size
+    pub fn assign_aux_booth(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+    ) -> Result<(), Error> {
+        match self.aux_generator {
+            Some((_, point)) => {
+                let aux = point.map(|point| make_mul_aux_booth(point, window_size));
+                let aux = self.assign_point(ctx, aux)?;
+                self.aux_booth_registry.insert(window_size, aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Assigns auxiliary point for multiplication with scalars that fit in
+    /// `max_bits` bits for a window_size
+    pub fn assign_aux_bounded(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+        max_bits: usize,
+    ) -> Result<(), Error> {
+        match self.aux_generator {
+            Some((_, point)) => {
+                let aux = point.map(|point| make_mul_aux_bounded(point, window_size, 1, max_bits));
+                let aux = self.assign_point(ctx, aux)?;
+                self.aux_bounded_registry
+                    .insert((window_size, max_bits), aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Reduces an element of the base field into the scalar field. Reduction
+    /// is witnessed as `base_elem = q * n + scalar` where `n` is the order of
+    /// the curve and `q` is bounded by the reduction, so it is only `0` or `1`
+    /// when moduli are close in size. Result is constrained to be less than
+    /// `n`.
+    pub fn reduce_to_scalar(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        base_elem: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let scalar_chip = self.scalar_field_chip();
+        let scalar = scalar_chip.reduce_external(ctx, base_elem)?;
+        scalar_chip.assert_in_field(ctx, &scalar)?;
+        Ok(scalar)
+    }
+
+    /// Constraints to ensure `x` coordinate of the point reduced modulo the
+    /// order of the curve equals to `x_target` as in the last step of ECDSA
+    /// verification where it is compared with `r`. Base field element is
+    /// moved to the scalar field with `reduce_to_scalar` so that `x >= n` is
+    /// handled. To compare the coordinate in the base field use
+    /// `assert_equal` of the base field chip with `point.x()`.
+    pub fn assert_x_equals(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        x_target: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let x = self.reduce_to_scalar(ctx, point.x())?;
+        self.scalar_field_chip().assert_equal(ctx, &x, x_target)
+    }
+
     /// Constraints to ensure `AssignedPoint` is on curve
+    /// `y^2 = x^3 + a * x + b`
     pub fn assert_is_on_curve(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -265,85 +706,414 @@ impl<
         let integer_chip = self.base_field_chip();
 
         let y_square = &integer_chip.square(ctx, point.y())?;
-        let x_square = &integer_chip.square(ctx, point.x())?;
-        let x_cube = &integer_chip.mul(ctx, point.x(), x_square)?;
-        let x_cube_b = &integer_chip.add_constant(ctx, x_cube, &self.parameter_b())?;
-        integer_chip.assert_equal(ctx, x_cube_b, y_square)?;
+        let rhs = &self.curve_equation_rhs(ctx, point.x())?;
+        integer_chip.assert_equal(ctx, rhs, y_square)?;
         Ok(())
     }
 
-    /// Constraints assert two `AssignedPoint`s are equal
-    pub fn assert_equal(
+    /// Constraints to ensure `AssignedPoint` is in the subgroup of the order
+    /// of the scalar field. Point is multiplied by `n - 1`, which is `-1` as a
+    /// scalar, and the result is compared with the negated point so that the
+    /// identity is never an intermediate value.
+    ///
+    /// The check is only meaningful for curves with cofactor greater than
+    /// one. Where cofactor is one every point on the curve is in the
+    /// subgroup and the check always passes. The pinned halo2curves has no
+    /// curve with cofactor greater than one over a prime base field, so a
+    /// point on the curve outside of the subgroup making the circuit
+    /// unsatisfiable is not covered by tests yet.
+    ///
+    /// Since auxiliary generator is added only once, aux must be assigned for
+    /// `window_size` and a single pair.
+    pub fn assert_in_subgroup(
         &self,
         ctx: &mut RegionCtx<'_, N>,
-        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
     ) -> Result<(), Error> {
-        let integer_chip = self.base_field_chip();
-        integer_chip.assert_equal(ctx, p0.x(), p1.x())?;
-        integer_chip.assert_equal(ctx, p0.y(), p1.y())
+        let scalar_chip = self.scalar_field_chip();
+        let minus_one = scalar_chip.assign_constant(ctx, -Emulated::ScalarExt::ONE)?;
+        let product = self.mul(ctx, point, &minus_one, window_size)?;
+        let negated = self.neg(ctx, point)?;
+        self.assert_equal(ctx, &product, &negated)
     }
 
-    /// Selects between 2 `AssignedPoint` determined by an `AssignedCondition`
-    pub fn select(
+    /// Returns `x^3 + a * x + b`
+    fn curve_equation_rhs(
         &self,
         ctx: &mut RegionCtx<'_, N>,
-        c: &AssignedCondition<N>,
-        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-        p2: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        x: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let integer_chip = self.base_field_chip();
-        let x = integer_chip.select(ctx, p1.x(), p2.x(), c)?;
-        let y = integer_chip.select(ctx, p1.y(), p2.y(), c)?;
-        Ok(AssignedPoint::new(x, y))
+
+        let x_square = &integer_chip.square(ctx, x)?;
+        let x_cube = &integer_chip.mul(ctx, x, x_square)?;
+        let x_cube_b = &integer_chip.add_constant(ctx, x_cube, &self.parameter_b())?;
+        if Self::has_parameter_a() {
+            let a_x = &integer_chip.mul_constant(ctx, x, &self.parameter_a())?;
+            integer_chip.add(ctx, x_cube_b, a_x)
+        } else {
+            Ok(x_cube_b.clone())
+        }
     }
 
-    /// Selects between an `AssignedPoint` and a point on the EC `Emulated`
-    /// determined by an `AssignedCondition`
-    pub fn select_or_assign(
+    /// Recovers a point from its `x` coordinate and the parity of its `y`
+    /// coordinate. Proof is unsatisfiable if `x` is not an abscissa of a
+    /// point on the curve.
+    pub fn from_compressed(
         &self,
         ctx: &mut RegionCtx<'_, N>,
-        c: &AssignedCondition<N>,
-        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-        p2: Emulated,
+        x: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        sign: &AssignedCondition<N>,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        use integer::maingate::MainGateInstructions;
+        let main_gate = self.main_gate();
         let integer_chip = self.base_field_chip();
-        let p2 = self.to_rns_point(p2);
-        let x = integer_chip.select_or_assign(ctx, p1.x(), p2.x(), c)?;
-        let y = integer_chip.select_or_assign(ctx, p1.y(), p2.y(), c)?;
-        Ok(AssignedPoint::new(x, y))
+
+        // `sqrt` constrains `y^2 = x^3 + a * x + b` when the returned condition
+        // is set, so the point is on curve without an additional check
+        let rhs = &self.curve_equation_rhs(ctx, x)?;
+        let (y, is_square) = integer_chip.sqrt(ctx, rhs)?;
+        main_gate.assert_one(ctx, &is_square)?;
+
+        // Flip the root if its parity doesn't match with the sign
+        let y_sign = &integer_chip.sign(ctx, &y)?;
+        let flip = &main_gate.xor(ctx, y_sign, sign)?;
+        let y_neg = &integer_chip.neg(ctx, &y)?;
+        let y = integer_chip.select(ctx, y_neg, &y, flip)?;
+
+        Ok(AssignedPoint::new(x.clone(), y))
     }
 
-    /// Normalizes an `AssignedPoint` by reducing each of its coordinates
-    pub fn normalize(
+    /// Maps a base field element to a point on the curve with try and
+    /// increment. Abscissa is the first of `u, u + 1, ...` that has a point
+    /// on the curve and the even root is taken as the ordinate, so the
+    /// mapping is deterministic. All [`MAP_TO_CURVE_ATTEMPTS`] candidates are
+    /// always tried so that the layout doesn't depend on `u`. Proof is
+    /// unsatisfiable if none of them is on the curve.
+    pub fn map_to_curve(
         &self,
         ctx: &mut RegionCtx<'_, N>,
-        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        u: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        use integer::maingate::MainGateInstructions;
+        let main_gate = self.main_gate();
         let integer_chip = self.base_field_chip();
-        let x = integer_chip.reduce(ctx, point.x())?;
-        let y = integer_chip.reduce(ctx, point.y())?;
-        Ok(AssignedPoint::new(x, y))
+
+        let rhs = &self.curve_equation_rhs(ctx, u)?;
+        let (mut y, mut found) = integer_chip.sqrt(ctx, rhs)?;
+        let mut x = u.clone();
+        for i in 1..MAP_TO_CURVE_ATTEMPTS {
+            let offset = Integer::from_fe(Emulated::Base::from(i as u64), self.rns_base());
+            let x_i = integer_chip.add_constant(ctx, u, &offset)?;
+            let rhs = &self.curve_equation_rhs(ctx, &x_i)?;
+            let (y_i, is_square) = integer_chip.sqrt(ctx, rhs)?;
+
+            // keep the first candidate that is on the curve
+            let not_found = &main_gate.not(ctx, &found)?;
+            let take = &main_gate.and(ctx, &is_square, not_found)?;
+            x = integer_chip.select(ctx, &x_i, &x, take)?;
+            y = integer_chip.select(ctx, &y_i, &y, take)?;
+            found = main_gate.or(ctx, &found, &is_square)?;
+        }
+        main_gate.assert_one(ctx, &found)?;
+
+        // `is_odd` works on the canonical root so prover can't pick the other
+        let is_odd = &integer_chip.is_odd(ctx, &y)?;
+        let y = integer_chip.neg_if(ctx, &y, is_odd)?;
+
+        let point = AssignedPoint::new(x, y);
+        self.assert_is_on_curve(ctx, &point)?;
+        Ok(point)
     }
 
-    /// Adds 2 distinct `AssignedPoints`
-    pub fn add(
+    /// Constraints assert two `AssignedPoint`s are equal
+    pub fn assert_equal(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        // guarantees that p0 != p1 or p0 != p1
-        // so that we can use unsafe addition formula which assumes operands are not
-        // equal addition to that we strictly disallow addition result to be
-        // point of infinity
-        self.base_field_chip()
-            .assert_not_equal(ctx, p0.x(), p1.x())?;
-
-        self._add_incomplete_unsafe(ctx, p0, p1)
+    ) -> Result<(), Error> {
+        let integer_chip = self.base_field_chip();
+        integer_chip.assert_equal(ctx, p0.x(), p1.x())?;
+        integer_chip.assert_equal(ctx, p0.y(), p1.y())
     }
 
-    /// Doubles an `AssignedPoint`
+    /// Constrains two `AssignedPoint`s to be equal with copy constraints on
+    /// the limbs of their coordinates. Unlike `assert_equal` no row is used
+    /// and points assigned in different regions can be connected. Limb
+    /// representations must be the same, so a point is expected to be equal
+    /// to its copy rather than to a point with unreduced coordinates.
+    pub fn copy_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        for (c0, c1) in [(p0.x(), p1.x()), (p0.y(), p1.y())] {
+            for (limb0, limb1) in c0.limbs().iter().zip(c1.limbs().iter()) {
+                ctx.constrain_equal(limb0.as_ref().cell(), limb1.as_ref().cell())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an `AssignedCondition` that is set if two `AssignedPoint`s are
+    /// equal. Coordinates are reduced and constrained to be in field so that
+    /// limb representations are unique and can be compared limb by limb.
+    pub fn is_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        use integer::maingate::MainGateInstructions;
+        let integer_chip = self.base_field_chip();
+        let main_gate = self.main_gate();
+
+        let mut limbs_equal: Vec<AssignedCondition<N>> = vec![];
+        for (c0, c1) in [(p0.x(), p1.x()), (p0.y(), p1.y())] {
+            let c0 = &integer_chip.reduce(ctx, c0)?;
+            let c1 = &integer_chip.reduce(ctx, c1)?;
+            integer_chip.assert_in_field(ctx, c0)?;
+            integer_chip.assert_in_field(ctx, c1)?;
+            for (limb0, limb1) in c0.limbs().iter().zip(c1.limbs().iter()) {
+                limbs_equal.push(main_gate.is_equal(ctx, limb0.as_ref(), limb1.as_ref())?);
+            }
+        }
+        main_gate.and_many(ctx, &limbs_equal)
+    }
+
+    /// Constraints two `AssignedPoint`s to be different. Coordinates are
+    /// canonicalized as in `is_equal` and a non-equality witness is used:
+    /// prover provides `w_i` for limb differences `d_i` such that
+    /// `sum_i d_i * w_i = 1`, which is unsatisfiable if all differences are
+    /// zero. The sum takes a row per limb on top of the subtractions rather
+    /// than an equality flag per limb.
+    pub fn assert_not_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        use integer::maingate::{CombinationOptionCommon, MainGateInstructions, Term};
+        let integer_chip = self.base_field_chip();
+        let main_gate = self.main_gate();
+
+        let mut differences: Vec<AssignedValue<N>> = vec![];
+        for (c0, c1) in [(p0.x(), p1.x()), (p0.y(), p1.y())] {
+            let c0 = &integer_chip.reduce(ctx, c0)?;
+            let c1 = &integer_chip.reduce(ctx, c1)?;
+            integer_chip.assert_in_field(ctx, c0)?;
+            integer_chip.assert_in_field(ctx, c1)?;
+            for (limb0, limb1) in c0.limbs().iter().zip(c1.limbs().iter()) {
+                differences.push(main_gate.sub(ctx, limb0.as_ref(), limb1.as_ref())?);
+            }
+        }
+
+        // witness is the inverse of the first non zero difference and zero
+        // for the others
+        let witnesses: Value<Vec<N>> = differences
+            .iter()
+            .map(|difference| difference.value().copied())
+            .collect::<Value<Vec<N>>>()
+            .map(|differences| {
+                let mut found = false;
+                differences
+                    .iter()
+                    .map(|difference| match Option::<N>::from(difference.invert()) {
+                        Some(inverse) if !found => {
+                            found = true;
+                            inverse
+                        }
+                        _ => N::ZERO,
+                    })
+                    .collect()
+            });
+        let witness = |i: usize| witnesses.as_ref().map(|witnesses| witnesses[i]);
+
+        // acc_i = d_i * w_i + acc_{i-1} and the last step is constrained to
+        // be one
+        let number_of_differences = differences.len();
+        let mut acc: Option<AssignedValue<N>> = None;
+        for (i, difference) in differences.iter().enumerate() {
+            let w = witness(i);
+            let product = difference.value().copied() * w;
+            let mut terms = vec![
+                Term::assigned_to_mul(difference),
+                Term::unassigned_to_mul(w),
+            ];
+            let next = match &acc {
+                Some(acc) => {
+                    terms.push(Term::assigned_to_add(acc));
+                    product + acc.value().copied()
+                }
+                None => product,
+            };
+            if i == number_of_differences - 1 {
+                main_gate.apply(
+                    ctx,
+                    terms,
+                    -N::ONE,
+                    CombinationOptionCommon::OneLinerMul.into(),
+                )?;
+            } else {
+                terms.push(Term::unassigned_to_sub(next));
+                let index = terms.len() - 1;
+                let mut assigned = main_gate.apply(
+                    ctx,
+                    terms,
+                    N::ZERO,
+                    CombinationOptionCommon::OneLinerMul.into(),
+                )?;
+                acc = Some(assigned.swap_remove(index));
+            }
+        }
+        Ok(())
+    }
+
+    /// Selects between 2 `AssignedPoint` determined by an `AssignedCondition`
+    ///
+    /// Same rows and constraints are laid out whatever the witness value of
+    /// the condition is, so selections don't leak the condition through the
+    /// layout of the circuit.
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let x = integer_chip.select(ctx, p1.x(), p2.x(), c)?;
+        let y = integer_chip.select(ctx, p1.y(), p2.y(), c)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Selects element-wise between two slices of `AssignedPoint` under a
+    /// single shared `AssignedCondition`: `a[i]` is picked if the condition
+    /// is set and `b[i]` otherwise.
+    ///
+    /// Each coordinate limb still takes one main gate row, since a row fits
+    /// exactly one `c * (a - b) + b` selection, so the layout matches the
+    /// one of selecting pairs one by one. Measured for `select_multi` at
+    /// window size 6, batching saves zero rows, see
+    /// `test_general_ecc_select_batch`.
+    ///
+    /// Returns `Error::Synthesis` if the slices are not of the same length.
+    pub fn select_batch(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        a: &[AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        b: &[AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<Vec<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error> {
+        if a.len() != b.len() {
+            return Err(Error::Synthesis);
+        }
+        a.iter()
+            .zip(b.iter())
+            .map(|(p1, p2)| self.select(ctx, c, p1, p2))
+            .collect()
+    }
+
+    /// Selects between an `AssignedPoint` and a point on the EC `Emulated`
+    /// determined by an `AssignedCondition`
+    pub fn select_or_assign(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: Emulated,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let p2 = self.to_rns_point(p2);
+        let x = integer_chip.select_or_assign(ctx, p1.x(), p2.x(), c)?;
+        let y = integer_chip.select_or_assign(ctx, p1.y(), p2.y(), c)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Normalizes an `AssignedPoint` by reducing each of its coordinates
+    pub fn normalize(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let x = integer_chip.reduce(ctx, point.x())?;
+        let y = integer_chip.reduce(ctx, point.y())?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Adds 2 distinct `AssignedPoints`
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // guarantees that p0 != p1 or p0 != p1
+        // so that we can use unsafe addition formula which assumes operands are not
+        // equal addition to that we strictly disallow addition result to be
+        // point of infinity
+        self.base_field_chip()
+            .assert_not_equal(ctx, p0.x(), p1.x())?;
+
+        self._add_incomplete_unsafe(ctx, p0, p1)
+    }
+
+    /// Subtracts `p1` from `p0` as `p0 + (-p1)` with `add`. Operands must have
+    /// distinct `x` coordinates, so `p0 = p1` and `p0 = -p1` make the circuit
+    /// unsatisfiable where the latter would be a doubling.
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let p1_neg = self.neg(ctx, p1)?;
+        self.add(ctx, p0, &p1_neg)
+    }
+
+    /// Sums all points with `add` in a binary tree so that the depth of the
+    /// additions is logarithmic in the number of points. Same as `add`, sum of
+    /// each pair of the tree must have operands with distinct `x`
+    /// coordinates, which is the case for independent random points, and
+    /// otherwise the circuit is unsatisfiable. Use `add_many_complete` if
+    /// operands may be equal or the identity.
+    ///
+    /// Returns `Error::Synthesis` if no point is given.
+    pub fn add_many(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        points: &[AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if points.is_empty() {
+            return Err(Error::Synthesis);
+        }
+        let mut layer = points.to_vec();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => self.add(ctx, a, b),
+                    [a] => Ok(a.clone()),
+                    _ => unreachable!(),
+                })
+                .collect::<Result<_, Error>>()?;
+        }
+        Ok(layer.pop().unwrap())
+    }
+
+    /// Doubles an `AssignedPoint`
+    ///
+    /// The point must be on the curve, which `assign_point` constrains, and
+    /// its `y` coordinate must not be zero. As an `AssignedPoint` is never
+    /// the identity and curves are assumed to have no point of order two,
+    /// these hold for any point assigned with this chip. A point that is not
+    /// on the curve leads to an erroneous result rather than an
+    /// unsatisfiable circuit. Use `double_complete` where the input may be
+    /// the identity.
     pub fn double(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -354,6 +1124,7 @@ impl<
     }
 
     /// Given an `AssignedPoint` $P$ computes P * 2^logn
+    /// Input is returned as is without any new rows if `logn` is zero
     pub fn double_n(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -369,16 +1140,30 @@ impl<
 
     /// Wrapper for `_ladder_incomplete`
     /// Given 2 `AssignedPoint` $P$ and $Q$ efficiently computes $2*P + Q$
+    ///
+    /// Formula is incomplete: `Q = P`, `Q = -P` and `Q = -2P` make the circuit
+    /// unsatisfiable since either `P + Q` or `(P + Q) + P` hits a zero
+    /// denominator. In `mul` and batch multiplication every accumulator and
+    /// table entry carries a distinct multiple of the auxiliary generator, so
+    /// an exceptional input implies a known discrete log relation between
+    /// the auxiliary generator and the input points. That holds for any
+    /// scalar and window size, thus exceptions are reached only with
+    /// negligible probability for a randomly sampled auxiliary generator. In
+    /// debug builds inputs are sampled and exceptional cases panic.
     pub fn ladder(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         to_double: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         to_add: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        #[cfg(debug_assertions)]
+        self.debug_assert_ladder_inputs(to_double, to_add);
         self._ladder_incomplete(ctx, to_double, to_add)
     }
 
     /// Returns the negative or inverse of an `AssignedPoint`
+    /// Points are affine with no identity representation, so note that
+    /// adding a point to its negative is unsatisfiable with `add`
     pub fn neg(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -388,6 +1173,36 @@ impl<
         let y_neg = integer_chip.neg(ctx, p.y())?;
         Ok(AssignedPoint::new(p.x().clone(), y_neg))
     }
+
+    /// Negates an `AssignedPoint` if the condition is set. Equivalent to
+    /// selecting between `neg(p)` and `p` but takes a single row per limb
+    /// rather than three.
+    pub fn neg_if(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let y = integer_chip.neg_if(ctx, p.y(), cond)?;
+        Ok(AssignedPoint::new(p.x().clone(), y))
+    }
+
+    /// Adds `to_add` to `acc` if the condition is set, otherwise returns a
+    /// copy of `acc`. The sum is laid out for both values of the condition,
+    /// so as in `add` the operands must have distinct `x` coordinates even
+    /// when the condition is not set. Along with `double` it serves
+    /// bit-serial double-and-add without a precomputed table.
+    pub fn add_if(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        acc: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        to_add: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let added = self.add(ctx, acc, to_add)?;
+        self.select(ctx, cond, &added, acc)
+    }
 }
 
 #[cfg(test)]
@@ -395,7 +1210,8 @@ mod tests {
     use std::marker::PhantomData;
     use std::rc::Rc;
 
-    use super::{AssignedPoint, EccConfig, GeneralEccChip, Point};
+    use super::{AssignedPoint, EccConfig, GeneralEccChip, HasEndomorphism, Point};
+    use crate::aux_generator_from_tag;
     use crate::halo2;
     use crate::halo2::halo2curves::{
         ff::{Field, FromUniformBytes, PrimeField},
@@ -405,23 +1221,25 @@ mod tests {
     use crate::integer::NUMBER_OF_LOOKUP_LIMBS;
     use crate::integer::{AssignedInteger, IntegerInstructions};
     use crate::maingate;
+    use crate::RowLimitExceeded;
     use halo2::arithmetic::CurveAffine;
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
     use integer::rns::Integer;
     use integer::Range;
-    use maingate::mock_prover_verify;
+    use maingate::{fe_to_big, mock_prover_verify, mock_prover_verify_fails};
     use maingate::{
         MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
     };
     use paste::paste;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     use crate::curves::bn256::{Fr as BnScalar, G1Affine as Bn256};
     use crate::curves::pasta::{
         EpAffine as Pallas, EqAffine as Vesta, Fp as PastaFp, Fq as PastaFq,
     };
     use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+    use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
 
     const NUMBER_OF_LIMBS: usize = 4;
     const BIT_LEN_LIMB: usize = 68;
@@ -622,6 +1440,9 @@ mod tests {
         run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
         run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
         run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        // `a != 0` so that doubling includes the `a` term
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
     }
 
     #[derive(Default, Clone, Debug)]
@@ -701,6 +1522,113 @@ mod tests {
         }
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestEccCompressedPublicInput<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        a: Value<C>,
+        b: Value<C>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccCompressedPublicInput<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            // sum is not normalized so that `x` is reduced before exposed
+            let sum = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = ecc_chip.assign_point(ctx, self.a)?;
+                    let b = ecc_chip.assign_point(ctx, self.b)?;
+                    ecc_chip.add(ctx, &a, &b)
+                },
+            )?;
+            ecc_chip.expose_compressed(layouter.namespace(|| "sum"), sum.clone(), 0)?;
+
+            layouter.assign_region(
+                || "region 1",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let recovered = ecc_chip.from_compressed_instance(ctx, 0)?;
+                    ecc_chip.assert_equal(ctx, &recovered, &sum)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_compressed_public_input() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let (rns_base, _, _) = setup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(0);
+            let rns_base = Rc::new(rns_base);
+
+            let a = C::Curve::random(OsRng).to_affine();
+            let b = C::Curve::random(OsRng).to_affine();
+            let sum: C = (a + b).into();
+            let circuit = TestEccCompressedPublicInput::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                a: Value::known(a),
+                b: Value::known(b),
+                ..Default::default()
+            };
+
+            let public_data = Point::new(Rc::clone(&rns_base), sum).public_compressed();
+            assert_eq!(public_data.len(), NUMBER_OF_LIMBS + 1);
+            mock_prover_verify(&circuit, vec![public_data.clone()]);
+
+            // flipped parity is the negated point
+            let mut flipped = public_data;
+            flipped[NUMBER_OF_LIMBS] = N::ONE - flipped[NUMBER_OF_LIMBS];
+            mock_prover_verify_fails(&circuit, vec![flipped]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
     #[test]
     fn test_general_ecc_public_input() {
         fn run<
@@ -748,14 +1676,16 @@ mod tests {
     }
 
     #[derive(Default, Clone, Debug)]
-    struct TestEccMul<
+    struct TestEccMulPublicScalar<
         C: CurveAffine,
         N: PrimeField,
         const NUMBER_OF_LIMBS: usize,
         const BIT_LEN_LIMB: usize,
     > {
-        window_size: usize,
+        point: Value<C>,
+        expected: Value<C>,
         aux_generator: C,
+        window_size: usize,
         _marker: PhantomData<N>,
     }
 
@@ -764,7 +1694,7 @@ mod tests {
             N: PrimeField,
             const NUMBER_OF_LIMBS: usize,
             const BIT_LEN_LIMB: usize,
-        > Circuit<N> for TestEccMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+        > Circuit<N> for TestEccMulPublicScalar<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
     {
         type Config = TestCircuitConfig;
         type FloorPlanner = SimpleFloorPlanner;
@@ -787,43 +1717,133 @@ mod tests {
             let ecc_chip_config = config.ecc_chip_config();
             let mut ecc_chip =
                 GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
-
-            layouter.assign_region(
-                || "assign aux values",
-                |region| {
-                    let offset = 0;
-                    let ctx = &mut RegionCtx::new(region, offset);
-                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
-                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
-                    ecc_chip.get_mul_aux(self.window_size, 1)?;
-                    Ok(())
-                },
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
             )?;
 
-            let scalar_chip = ecc_chip.scalar_field_chip();
-
             layouter.assign_region(
                 || "region mul",
                 |region| {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
 
-                    let base = C::Curve::random(OsRng);
-                    let s = C::Scalar::random(OsRng);
-                    let result = base * s;
+                    let point = ecc_chip.assign_point(ctx, self.point)?;
+                    let result = ecc_chip.mul_public_scalar(ctx, &point, 2, self.window_size)?;
+                    let expected = ecc_chip.assign_point(ctx, self.expected)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)
+                },
+            )?;
 
-                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
-                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
-                    let s = scalar_chip.assign_integer(
-                        ctx,
-                        Value::known(s).into(),
-                        Range::Remainder,
-                    )?;
-                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+            config.config_range(&mut layouter)?;
 
-                    let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
-                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_public_scalar() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >(
+            window_size: usize,
+        ) {
+            let point = C::Curve::random(OsRng);
+            let s = C::Scalar::random(OsRng);
+            let circuit = TestEccMulPublicScalar::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point: Value::known(point.to_affine()),
+                expected: Value::known((point * s).to_affine()),
+                aux_generator: C::Curve::random(OsRng).to_affine(),
+                window_size,
+                ..Default::default()
+            };
+
+            // scalar bits start from the third row of the instance column
+            let mut public_data = vec![N::ZERO, N::ZERO];
+            public_data.extend(
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::public_scalar_bits(s),
+            );
+            mock_prover_verify(&circuit, vec![public_data]);
+
+            // product must follow the public scalar
+            let mut public_data = vec![N::ZERO, N::ZERO];
+            public_data.extend(
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::public_scalar_bits(
+                    s + C::Scalar::ONE,
+                ),
+            );
+            mock_prover_verify_fails(&circuit, vec![public_data]);
+        }
+
+        for window_size in 1..3 {
+            run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(window_size);
+            run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(window_size);
+            run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(window_size);
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccAssertNotEqual<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        p0: Value<C>,
+        p1: Value<C>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccAssertNotEqual<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
 
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let p0 = ecc_chip.assign_point(ctx, self.p0)?;
+                    let p1 = ecc_chip.assign_point(ctx, self.p1)?;
+
+                    // fewer rows than the equality flag
+                    let offset = ctx.offset();
+                    ecc_chip.assert_not_equal(ctx, &p0, &p1)?;
+                    let rows = ctx.offset() - offset;
+                    let offset = ctx.offset();
+                    ecc_chip.is_equal(ctx, &p0, &p1)?;
+                    assert!(rows < ctx.offset() - offset);
                     Ok(())
                 },
             )?;
@@ -835,45 +1855,141 @@ mod tests {
     }
 
     #[test]
-    fn test_general_ecc_mul_circuit() {
+    fn test_general_ecc_assert_not_equal() {
         fn run<
             C: CurveAffine,
             N: FromUniformBytes<64> + Ord,
             const NUMBER_OF_LIMBS: usize,
             const BIT_LEN_LIMB: usize,
         >() {
-            for window_size in 1..5 {
-                let aux_generator = C::Curve::random(OsRng).to_affine();
-
-                let circuit = TestEccMul::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-                    aux_generator,
-                    window_size,
+            let p = C::Curve::random(OsRng).to_affine();
+            let q = C::Curve::random(OsRng).to_affine();
+
+            // distinct points and points that only differ in `y`
+            for (p0, p1) in [(p, q), (p, -p)] {
+                let circuit = TestEccAssertNotEqual::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    p0: Value::known(p0),
+                    p1: Value::known(p1),
                     ..Default::default()
                 };
-                let instance = vec![vec![]];
-                mock_prover_verify(&circuit, instance);
+                mock_prover_verify(&circuit, vec![vec![]]);
             }
+
+            let circuit = TestEccAssertNotEqual::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                p0: Value::known(p),
+                p1: Value::known(p),
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
         }
 
         run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
 
-        run::<Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    #[derive(Default, Clone, Debug)]
+    struct TestEccCopyEqual<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        point: Value<C>,
+        copied: Value<C>,
+        _marker: PhantomData<N>,
+    }
 
-        run::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccCopyEqual<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
 
-        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
-        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            let point = layouter.assign_region(
+                || "region point",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_point(ctx, self.point)
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region copy",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let copied = ecc_chip.assign_point(ctx, self.copied)?;
+
+                    // copy constraints take no rows
+                    let offset = ctx.offset();
+                    ecc_chip.copy_equal(ctx, &point, &copied)?;
+                    assert_eq!(ctx.offset(), offset);
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_copy_equal() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let point = C::Curve::random(OsRng).to_affine();
+            let circuit = TestEccCopyEqual::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point: Value::known(point),
+                copied: Value::known(point),
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+
+            let circuit = TestEccCopyEqual::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point: Value::known(point),
+                copied: Value::known(C::Curve::random(OsRng).to_affine()),
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
         run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
     }
 
     #[derive(Default, Clone, Debug)]
-    struct TestEccBatchMul<
+    struct TestEccMul<
         C: CurveAffine,
         N: PrimeField,
         const NUMBER_OF_LIMBS: usize,
@@ -881,7 +1997,6 @@ mod tests {
     > {
         window_size: usize,
         aux_generator: C,
-        number_of_pairs: usize,
         _marker: PhantomData<N>,
     }
 
@@ -890,7 +2005,7 @@ mod tests {
             N: PrimeField,
             const NUMBER_OF_LIMBS: usize,
             const BIT_LEN_LIMB: usize,
-        > Circuit<N> for TestEccBatchMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+        > Circuit<N> for TestEccMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
     {
         type Config = TestCircuitConfig;
         type FloorPlanner = SimpleFloorPlanner;
@@ -905,7 +2020,6 @@ mod tests {
             TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
         }
 
-        #[allow(clippy::type_complexity)]
         fn synthesize(
             &self,
             config: Self::Config,
@@ -921,8 +2035,8 @@ mod tests {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
                     ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
-                    ecc_chip.assign_aux(ctx, self.window_size, self.number_of_pairs)?;
-                    ecc_chip.get_mul_aux(self.window_size, self.number_of_pairs)?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    ecc_chip.get_mul_aux(self.window_size, 1)?;
                     Ok(())
                 },
             )?;
@@ -935,29 +2049,19 @@ mod tests {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
 
-                    let mut acc = C::Curve::identity();
-                    let pairs: Vec<(
-                        AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-                        AssignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
-                    )> = (0..self.number_of_pairs)
-                        .map(|_| {
-                            let base = C::Curve::random(OsRng);
-                            let s = C::Scalar::random(OsRng);
-                            acc += base * s;
-                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
-                            let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
-                            let s = scalar_chip.assign_integer(
-                                ctx,
-                                Value::known(s).into(),
-                                Range::Remainder,
-                            )?;
-                            Ok((base, s))
-                        })
-                        .collect::<Result<_, Error>>()?;
+                    let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                    let s = C::Scalar::random(OsRng);
+                    let result = base_value * s;
 
-                    let result_0 = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
-                    let result_1 =
-                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
                     ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
 
                     Ok(())
@@ -970,43 +2074,6311 @@ mod tests {
         }
     }
 
-    macro_rules! test_general_ecc_mul_batch_circuit {
-        ($C:ty, $N:ty, $NUMBER_OF_LIMBS:expr, $BIT_LEN_LIMB:expr) => {
-            paste! {
-                #[test]
-                fn [<test_general_ecc_mul_batch_circuit_ $C:lower _ $N:lower>]() {
-                    for number_of_pairs in 5..7 {
-                        for window_size in 1..3 {
-                            let aux_generator = <$C as PrimeCurveAffine>::Curve::random(OsRng).to_affine();
+    impl_ecc_test_circuit!(
+        TestEccAuxFromTag,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let number_of_pairs = 2;
+            let tag = b"test aux";
 
-                            let circuit = TestEccBatchMul::<$C, $N, $NUMBER_OF_LIMBS, $BIT_LEN_LIMB> {
-                                aux_generator,
-                                window_size,
-                                number_of_pairs,
-                                ..Default::default()
-                            };
-                            let instance = vec![vec![]];
-                            mock_prover_verify(&circuit, instance);
-                        }
-                    }
-                }
-            }
-        }
-    }
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.set_aux_from_tag(ctx, self.window_size, number_of_pairs, tag)
+                },
+            )?;
 
-    test_general_ecc_mul_batch_circuit!(Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+            // derivation is deterministic and separated by the tag
+            let aux_generator = aux_generator_from_tag::<C>(tag);
+            assert_eq!(aux_generator, aux_generator_from_tag::<C>(tag));
+            assert_ne!(aux_generator, aux_generator_from_tag::<C>(b"other aux"));
 
-    test_general_ecc_mul_batch_circuit!(Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+            // to_sub = -(to_add * sum_i 2^(i * window_size) * (2^n_pairs - 1))
+            let number_of_bits = C::Scalar::NUM_BITS as usize;
+            let number_of_windows = (number_of_bits + self.window_size - 1) / self.window_size;
+            let k_0 = (0..number_of_windows).fold(C::Scalar::ZERO, |acc, _| {
+                acc * C::Scalar::from(1 << self.window_size) + C::Scalar::ONE
+            });
+            let k_1 = C::Scalar::from((1 << number_of_pairs) - 1);
+            let to_sub = (-(aux_generator * (k_0 * k_1))).to_affine();
 
-    test_general_ecc_mul_batch_circuit!(Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+            let aux = ecc_chip.get_mul_aux(self.window_size, number_of_pairs)?;
+            assert_eq!(aux.to_add.debug_value::<C>(), Some(aux_generator));
+            assert_eq!(aux.to_sub.debug_value::<C>(), Some(to_sub));
 
-    test_general_ecc_mul_batch_circuit!(Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-    test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut pairs = vec![];
+                    let mut result = C::Curve::identity();
+                    for _ in 0..number_of_pairs {
+                        let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                        let s = C::Scalar::random(OsRng);
+                        result = result + base_value * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        pairs.push((base, s));
+                    }
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_aux_from_tag() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccAuxFromTag,
+                window_size,
+                [Pallas, BnScalar],
+                [Vesta, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSetupAux,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let configs = [(1, 1), (2, 1), (2, 3), (3, 2)];
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // aux generator must be assigned first
+                    assert!(matches!(
+                        ecc_chip.setup_aux(ctx, &configs),
+                        Err(Error::Synthesis)
+                    ));
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    // invalid pairs are rejected before anything is assigned
+                    for invalid in [(0, 1), (1, 0), (crate::MAX_WINDOW_SIZE + 1, 1)] {
+                        assert!(matches!(
+                            ecc_chip.setup_aux(ctx, &[(1, 1), invalid]),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(ecc_chip.get_mul_aux(1, 1).is_err());
+                    }
+                    ecc_chip.setup_aux(ctx, &configs)
+                },
+            )?;
+
+            for (window_size, number_of_pairs) in configs {
+                assert!(ecc_chip.get_mul_aux(window_size, number_of_pairs).is_ok());
+            }
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for (window_size, number_of_pairs) in configs {
+                        let mut pairs = vec![];
+                        let mut result = C::Curve::identity();
+                        for _ in 0..number_of_pairs {
+                            let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                            let s = C::Scalar::random(OsRng);
+                            result = result + base_value * s;
+
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            pairs.push((base, s));
+                        }
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = if number_of_pairs == 1 {
+                            let (base, s) = &pairs[0];
+                            ecc_chip.mul(ctx, base, s, window_size)?
+                        } else {
+                            ecc_chip.mul_batch_1d_horizontal(ctx, pairs, window_size)?
+                        };
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    }
+
+                    // combinations that are not configured keep failing
+                    let mut pairs = vec![];
+                    for _ in 0..2 {
+                        let (_, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                        let s = C::Scalar::random(OsRng);
+                        let s = ecc_chip.new_unassigned_scalar(Value::known(s));
+                        let s = scalar_chip.assign_integer(ctx, s, Range::Remainder)?;
+                        pairs.push((base, s));
+                    }
+                    let (base, s) = &pairs[0];
+                    assert!(matches!(
+                        ecc_chip.mul(ctx, base, s, 3),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, 1),
+                        Err(Error::Synthesis)
+                    ));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_setup_aux() {
+        run_ecc_test_circuit!(TestEccSetupAux, 0, [Pallas, BnScalar], [Secp256k1, PastaFq]);
+    }
+
+    #[test]
+    fn test_general_ecc_mul_circuit() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for window_size in 1..5 {
+                let aux_generator = C::Curve::random(OsRng).to_affine();
+
+                let circuit = TestEccMul::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    aux_generator,
+                    window_size,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    macro_rules! impl_ecc_test_circuit {
+        ($circuit_name:ident, $( $synth:tt )*) => {
+            // not every circuit makes use of multiplication parameters
+            #[allow(dead_code)]
+            #[derive(Default, Clone, Debug)]
+            struct $circuit_name<
+                C: CurveAffine,
+                N: PrimeField,
+                const NUMBER_OF_LIMBS: usize,
+                const BIT_LEN_LIMB: usize,
+            > {
+                window_size: usize,
+                aux_generator: C,
+                _marker: PhantomData<N>,
+            }
+
+            impl<
+                    C: CurveAffine,
+                    N: PrimeField,
+                    const NUMBER_OF_LIMBS: usize,
+                    const BIT_LEN_LIMB: usize,
+                > Circuit<N> for $circuit_name<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+            {
+                type Config = TestCircuitConfig;
+                type FloorPlanner = SimpleFloorPlanner;
+                #[cfg(feature = "circuit-params")]
+                type Params = ();
+
+                fn without_witnesses(&self) -> Self {
+                    unimplemented!()
+                }
+
+                fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+                    TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+                }
+
+                $( $synth )*
+            }
+        };
+    }
+
+    macro_rules! run_ecc_test_circuit {
+        ($circuit_name:ident, $window_size:expr) => {
+            run_ecc_test_circuit!(
+                $circuit_name,
+                $window_size,
+                [Pallas, BnScalar],
+                [Pallas, PastaFp],
+                [Pallas, PastaFq],
+                [Vesta, BnScalar],
+                [Vesta, PastaFp],
+                [Vesta, PastaFq],
+                [Bn256, BnScalar],
+                [Bn256, PastaFp],
+                [Bn256, PastaFq],
+                [Secp256k1, BnScalar],
+                [Secp256k1, PastaFp],
+                [Secp256k1, PastaFq]
+            )
+        };
+        ($circuit_name:ident, $window_size:expr, $([$C:ty, $N:ty]),*) => {
+            $(
+                let aux_generator = <$C as PrimeCurveAffine>::Curve::random(OsRng).to_affine();
+                let circuit = $circuit_name::<$C, $N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    aux_generator,
+                    window_size: $window_size,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            )*
+        };
+    }
+
+    fn assign_aux<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    >(
+        ecc_chip: &mut GeneralEccChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        layouter: &mut impl Layouter<N>,
+        aux_generator: C,
+        window_size: usize,
+        number_of_pairs: usize,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "assign aux values",
+            |region| {
+                let offset = 0;
+                let ctx = &mut RegionCtx::new(region, offset);
+                ecc_chip.assign_aux_generator(ctx, Value::known(aux_generator))?;
+                ecc_chip.assign_aux(ctx, window_size, number_of_pairs)?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Samples a random point and assigns it as a witness that is constrained
+    /// to be on the curve. Identity is resampled since it has no affine
+    /// coordinates to assign. Returns the sampled value along with the
+    /// assigned point.
+    #[allow(clippy::type_complexity)]
+    fn assign_random_point<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    >(
+        ecc_chip: &GeneralEccChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ctx: &mut RegionCtx<'_, N>,
+        mut rng: impl RngCore,
+    ) -> Result<
+        (
+            C::Curve,
+            AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let point = loop {
+            let point = C::Curve::random(&mut rng);
+            if !bool::from(point.is_identity()) {
+                break point;
+            }
+        };
+        assert!(bool::from(point.to_affine().is_on_curve()));
+        let assigned = ecc_chip.assign_point(ctx, Value::known(point.to_affine()))?;
+        Ok((point, assigned))
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulUnpadded,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for _ in 0..2 {
+                        let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                        let s = C::Scalar::random(OsRng);
+                        let result = base_value * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                        let offset = ctx.offset();
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let padded_rows = ctx.offset() - offset;
+
+                        let offset = ctx.offset();
+                        let result_2 = ecc_chip.mul_unpadded(ctx, &base, &s, self.window_size)?;
+                        let unpadded_rows = ctx.offset() - offset;
+
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+                        assert!(unpadded_rows <= padded_rows);
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_unpadded_circuit() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulUnpadded, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulBoundary,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                    // `n - 1` and `n - 2` set all high bits so the top window is
+                    // filled up to the padding
+                    let one = C::Scalar::ONE;
+                    let two = one.double();
+                    for s in [one, two, -one, -two] {
+                        let result = base_value * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let result_2 = ecc_chip.mul_unpadded(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_boundary_scalars() {
+        // scalar fields of 256, 255 and 254 bits leave a partial top window
+        // for different window sizes
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(
+                TestEccMulBoundary,
+                window_size,
+                [Secp256k1, BnScalar],
+                [Pallas, BnScalar],
+                [Bn256, BnScalar]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulBatchUpTo,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let max_number_of_pairs = 5;
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    assert!(matches!(
+                        ecc_chip.assign_aux_up_to(ctx, self.window_size, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.assign_aux_up_to(ctx, crate::MAX_WINDOW_SIZE + 1, 2),
+                        Err(Error::Synthesis)
+                    ));
+                    ecc_chip.assign_aux_up_to(ctx, self.window_size, max_number_of_pairs)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for number_of_pairs in 1..=max_number_of_pairs + 1 {
+                        let mut pairs = vec![];
+                        let mut result = C::Curve::identity();
+                        for _ in 0..number_of_pairs {
+                            let (base_value, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                            let s = C::Scalar::random(OsRng);
+                            result = result + base_value * s;
+
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            pairs.push((base, s));
+                        }
+
+                        let offset = ctx.offset();
+                        let result_1 =
+                            ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size);
+                        if number_of_pairs > max_number_of_pairs {
+                            assert!(matches!(result_1, Err(Error::Synthesis)));
+                            continue;
+                        }
+                        // derived auxiliary points are not recomputed in the batch
+                        assert_eq!(
+                            ctx.offset() - offset,
+                            GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_batch_row_estimate(
+                                number_of_pairs,
+                                self.window_size
+                            )?
+                        );
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1?)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_up_to() {
+        for window_size in 2..4 {
+            run_ecc_test_circuit!(
+                TestEccMulBatchUpTo,
+                window_size,
+                [Pallas, BnScalar],
+                [Secp256k1, BnScalar]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulUnfinalized,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut points = vec![];
+                    let mut scalars = vec![];
+                    for _ in 0..2 {
+                        let (_, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                        let s = C::Scalar::random(OsRng);
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        points.push(base);
+                        scalars.push(scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?);
+                    }
+
+                    // finalized alone is the same with `mul`
+                    let (acc, correction) =
+                        ecc_chip.mul_unfinalized(ctx, &points[0], &scalars[0], self.window_size)?;
+                    let r_0 = ecc_chip.finalize_mul(ctx, &acc, &correction)?;
+                    let r_1 = ecc_chip.mul(ctx, &points[0], &scalars[0], self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &r_0, &r_1)?;
+
+                    // sum of accumulators is corrected once
+                    let (acc_0, correction_0) =
+                        ecc_chip.mul_unfinalized(ctx, &points[0], &scalars[0], self.window_size)?;
+                    let (acc_1, correction_1) =
+                        ecc_chip.mul_unfinalized(ctx, &points[1], &scalars[1], self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &correction_0, &correction_1)?;
+                    let acc = ecc_chip.add(ctx, &acc_0, &acc_1)?;
+                    let correction = ecc_chip.double(ctx, &correction_0)?;
+                    let combined = ecc_chip.finalize_mul(ctx, &acc, &correction)?;
+
+                    let r_0 = ecc_chip.mul(ctx, &points[0], &scalars[0], self.window_size)?;
+                    let r_1 = ecc_chip.mul(ctx, &points[1], &scalars[1], self.window_size)?;
+                    let expected = ecc_chip.add(ctx, &r_0, &r_1)?;
+                    ecc_chip.assert_equal(ctx, &combined, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_unfinalized() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(
+                TestEccMulUnfinalized,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulWithTrace,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let (_, base) = assign_random_point(&ecc_chip, ctx, OsRng)?;
+                    let s = C::Scalar::random(OsRng);
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+
+                    let offset = ctx.offset();
+                    let (result, trace) =
+                        ecc_chip.mul_with_trace(ctx, &base, &s, self.window_size)?;
+                    let trace_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let expected = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    let mul_rows = ctx.offset() - offset;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    // one snapshot for each window and no additional constraint
+                    let number_of_bits = C::Scalar::NUM_BITS as usize;
+                    let number_of_windows =
+                        (number_of_bits + self.window_size - 1) / self.window_size;
+                    assert_eq!(trace.len(), number_of_windows);
+                    assert_eq!(trace_rows, mul_rows);
+
+                    let last = trace.last().unwrap();
+                    assert!(last.shares_cells(&result));
+                    ecc_chip.assert_equal(ctx, last, &result)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_with_trace() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(TestEccMulWithTrace, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccNeg,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let p_neg = -p;
+
+                    let p = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let q = &ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+                    let p_neg_0 = &ecc_chip.assign_point(ctx, Value::known(p_neg.into()))?;
+                    let p_neg_1 = &ecc_chip.neg(ctx, p)?;
+                    ecc_chip.assert_equal(ctx, p_neg_0, p_neg_1)?;
+
+                    // (q + p) - p = q
+                    let r = &ecc_chip.add(ctx, q, p)?;
+                    let r = &ecc_chip.add(ctx, r, p_neg_1)?;
+                    ecc_chip.assert_equal(ctx, r, q)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_neg_circuit() {
+        run_ecc_test_circuit!(TestEccNeg, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSub,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for _ in 0..4 {
+                        let p = C::Curve::random(OsRng);
+                        let q = C::Curve::random(OsRng);
+                        let expected = p - q;
+
+                        let p = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                        let q = &ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+                        let expected =
+                            &ecc_chip.assign_point(ctx, Value::known(expected.into()))?;
+
+                        let q_neg = &ecc_chip.neg(ctx, q)?;
+                        let result_0 = &ecc_chip.add(ctx, p, q_neg)?;
+                        let result_1 = &ecc_chip.sub(ctx, p, q)?;
+                        ecc_chip.assert_equal(ctx, result_0, expected)?;
+                        ecc_chip.assert_equal(ctx, result_1, expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_sub_circuit() {
+        run_ecc_test_circuit!(TestEccSub, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccNegIf,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for cond in [N::ZERO, N::ONE] {
+                        let p = C::Curve::random(OsRng);
+                        let expected = if cond == N::ONE { -p } else { p };
+
+                        let p = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                        let expected =
+                            &ecc_chip.assign_point(ctx, Value::known(expected.into()))?;
+                        let cond = &main_gate.assign_bit(ctx, Value::known(cond))?;
+
+                        let offset = ctx.offset();
+                        let p_neg = &ecc_chip.neg(ctx, p)?;
+                        let result_0 = &ecc_chip.select(ctx, cond, p_neg, p)?;
+                        let naive_rows = ctx.offset() - offset;
+
+                        let offset = ctx.offset();
+                        let result_1 = &ecc_chip.neg_if(ctx, p, cond)?;
+                        let fused_rows = ctx.offset() - offset;
+
+                        ecc_chip.assert_equal(ctx, result_0, expected)?;
+                        ecc_chip.assert_equal(ctx, result_1, expected)?;
+                        assert_eq!(naive_rows, 3 * fused_rows);
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_neg_if_circuit() {
+        run_ecc_test_circuit!(TestEccNegIf, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccAddComplete,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let p_assigned = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let q_assigned = &ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+                    let p_neg = &ecc_chip.neg(ctx, p_assigned)?;
+
+                    let p_assigned = &ecc_chip.to_point_or_identity(ctx, p_assigned)?;
+                    let q_assigned = &ecc_chip.to_point_or_identity(ctx, q_assigned)?;
+                    let p_neg = &ecc_chip.to_point_or_identity(ctx, p_neg)?;
+                    let identity = &ecc_chip.assign_identity(ctx)?;
+
+                    for (a, b, expected) in [
+                        (p_assigned, q_assigned, Some(p + q)),
+                        (p_assigned, p_assigned, Some(p + p)),
+                        (p_assigned, p_neg, None),
+                        (p_assigned, identity, Some(p)),
+                        (identity, p_assigned, Some(p)),
+                        (identity, identity, None),
+                    ] {
+                        let r = ecc_chip.add_complete(ctx, a, b)?;
+                        match expected {
+                            Some(expected) => {
+                                main_gate.assert_zero(ctx, r.is_identity())?;
+                                let expected =
+                                    ecc_chip.assign_point(ctx, Value::known(expected.into()))?;
+                                ecc_chip.assert_equal(ctx, r.point(), &expected)?;
+                            }
+                            None => main_gate.assert_one(ctx, r.is_identity())?,
+                        }
+                    }
+
+                    // multiplication by zero ends up in identity
+                    let point = ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let zero = ecc_chip.new_unassigned_scalar(Value::known(C::Scalar::ZERO));
+                    let zero = scalar_chip.assign_integer(ctx, zero, Range::Remainder)?;
+                    let r = ecc_chip.mul_or_identity(ctx, &point, &zero, self.window_size)?;
+                    main_gate.assert_one(ctx, r.is_identity())?;
+
+                    let s = C::Scalar::random(OsRng);
+                    let scalar = ecc_chip.new_unassigned_scalar(Value::known(s));
+                    let scalar = scalar_chip.assign_integer(ctx, scalar, Range::Remainder)?;
+                    let r = ecc_chip.mul_or_identity(ctx, &point, &scalar, self.window_size)?;
+                    main_gate.assert_zero(ctx, r.is_identity())?;
+                    let expected = ecc_chip.assign_point(ctx, Value::known((p * s).into()))?;
+                    ecc_chip.assert_equal(ctx, r.point(), &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_add_complete() {
+        run_ecc_test_circuit!(
+            TestEccAddComplete,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccDoubleComplete,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let p_assigned = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let expected = &ecc_chip.assign_point(ctx, Value::known(p.double().into()))?;
+
+                    // `double` agrees with the complete addition of the point to itself
+                    let doubled = &ecc_chip.double(ctx, p_assigned)?;
+                    ecc_chip.assert_equal(ctx, doubled, expected)?;
+                    let p_assigned = &ecc_chip.to_point_or_identity(ctx, p_assigned)?;
+                    let sum = ecc_chip.add_complete(ctx, p_assigned, p_assigned)?;
+                    main_gate.assert_zero(ctx, sum.is_identity())?;
+                    ecc_chip.assert_equal(ctx, sum.point(), doubled)?;
+
+                    let r = ecc_chip.double_complete(ctx, p_assigned)?;
+                    main_gate.assert_zero(ctx, r.is_identity())?;
+                    ecc_chip.assert_equal(ctx, r.point(), expected)?;
+
+                    // doubling the identity ends up in identity
+                    let identity = &ecc_chip.assign_identity(ctx)?;
+                    let r = ecc_chip.double_complete(ctx, identity)?;
+                    main_gate.assert_one(ctx, r.is_identity())?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_double_complete() {
+        run_ecc_test_circuit!(
+            TestEccDoubleComplete,
+            0,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp],
+            [Secp256r1, BnScalar]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccFieldChips,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let base_chip = ecc_chip.base_field_chip();
+            assert_ne!(
+                scalar_chip.rns().wrong_modulus,
+                base_chip.rns().wrong_modulus
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // same integers are multiplied under distinct moduli
+                    let (a, b) = (
+                        (u128::from(OsRng.next_u64()) << 64) | u128::from(OsRng.next_u64()),
+                        (u128::from(OsRng.next_u64()) << 64) | u128::from(OsRng.next_u64()),
+                    );
+                    let (a_scalar, b_scalar) = (C::Scalar::from_u128(a), C::Scalar::from_u128(b));
+                    let (a_base, b_base) = (C::Base::from_u128(a), C::Base::from_u128(b));
+                    assert_ne!(fe_to_big(a_scalar * b_scalar), fe_to_big(a_base * b_base));
+
+                    let a = ecc_chip.new_unassigned_scalar(Value::known(a_scalar));
+                    let b = ecc_chip.new_unassigned_scalar(Value::known(b_scalar));
+                    let expected =
+                        ecc_chip.new_unassigned_scalar(Value::known(a_scalar * b_scalar));
+                    let a = &scalar_chip.assign_integer(ctx, a, Range::Remainder)?;
+                    let b = &scalar_chip.assign_integer(ctx, b, Range::Remainder)?;
+                    let expected = &scalar_chip.assign_integer(ctx, expected, Range::Remainder)?;
+                    let c = scalar_chip.mul(ctx, a, b)?;
+                    scalar_chip.assert_equal(ctx, &c, expected)?;
+
+                    let a = ecc_chip.new_unassigned_base(Value::known(a_base));
+                    let b = ecc_chip.new_unassigned_base(Value::known(b_base));
+                    let expected = ecc_chip.new_unassigned_base(Value::known(a_base * b_base));
+                    let a = &base_chip.assign_integer(ctx, a, Range::Remainder)?;
+                    let b = &base_chip.assign_integer(ctx, b, Range::Remainder)?;
+                    let expected = &base_chip.assign_integer(ctx, expected, Range::Remainder)?;
+                    let c = base_chip.mul(ctx, a, b)?;
+                    base_chip.assert_equal(ctx, &c, expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_field_chips() {
+        run_ecc_test_circuit!(
+            TestEccFieldChips,
+            0,
+            [Pallas, BnScalar],
+            [Secp256k1, BnScalar]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccAddIf,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let acc = C::Curve::random(OsRng);
+                    let to_add = C::Curve::random(OsRng);
+                    let acc_assigned = &ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+                    let to_add_assigned =
+                        &ecc_chip.assign_point(ctx, Value::known(to_add.into()))?;
+                    for (cond, expected) in [(N::ZERO, acc), (N::ONE, acc + to_add)] {
+                        let cond = &main_gate.assign_bit(ctx, Value::known(cond))?;
+                        let expected =
+                            &ecc_chip.assign_point(ctx, Value::known(expected.into()))?;
+                        let result = ecc_chip.add_if(ctx, acc_assigned, to_add_assigned, cond)?;
+                        ecc_chip.assert_equal(ctx, &result, expected)?;
+                    }
+
+                    // bit-serial double-and-add starting from a random offset
+                    // that is cancelled at the end
+                    let base = C::Curve::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let bits = scalar_chip.decompose(ctx, &s)?;
+
+                    let start = C::Curve::random(OsRng);
+                    let correction = -(0..bits.len()).fold(start, |acc, _| acc.double());
+                    let mut acc = ecc_chip.assign_point(ctx, Value::known(start.into()))?;
+                    for bit in bits.iter().rev() {
+                        acc = ecc_chip.double(ctx, &acc)?;
+                        acc = ecc_chip.add_if(ctx, &acc, &base, bit)?;
+                    }
+                    let correction = ecc_chip.assign_point(ctx, Value::known(correction.into()))?;
+                    let result_0 = ecc_chip.add(ctx, &acc, &correction)?;
+
+                    let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_add_if() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(TestEccAddIf, window_size, [Pallas, BnScalar]);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSelectOrIdentity,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let p_assigned = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let q_assigned = &ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+                    let q_assigned = &ecc_chip.to_point_or_identity(ctx, q_assigned)?;
+
+                    for (cond, expected) in [(N::ONE, p + q), (N::ZERO, q)] {
+                        let cond = main_gate.assign_bit(ctx, Value::known(cond))?;
+                        let selected = ecc_chip.select_or_identity(ctx, &cond, p_assigned)?;
+                        let r = ecc_chip.add_complete(ctx, &selected, q_assigned)?;
+                        main_gate.assert_zero(ctx, r.is_identity())?;
+                        let expected = ecc_chip.assign_point(ctx, Value::known(expected.into()))?;
+                        ecc_chip.assert_equal(ctx, r.point(), &expected)?;
+
+                        // identity is absorbed from either side
+                        let r = ecc_chip.add_complete(ctx, q_assigned, &selected)?;
+                        ecc_chip.assert_equal(ctx, r.point(), &expected)?;
+                    }
+
+                    // both operands may be dropped
+                    let cond = main_gate.assign_bit(ctx, Value::known(N::ZERO))?;
+                    let a = ecc_chip.select_or_identity(ctx, &cond, p_assigned)?;
+                    let b = ecc_chip.select_or_identity(ctx, &cond, q_assigned.point())?;
+                    let r = ecc_chip.add_complete(ctx, &a, &b)?;
+                    main_gate.assert_one(ctx, r.is_identity())?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_select_or_identity() {
+        run_ecc_test_circuit!(TestEccSelectOrIdentity, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccAddMany,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    assert!(ecc_chip.add_many(ctx, &[]).is_err());
+
+                    for number_of_points in 1..6 {
+                        let points: Vec<C::Curve> = (0..number_of_points)
+                            .map(|_| C::Curve::random(OsRng))
+                            .collect();
+                        let assigned = points
+                            .iter()
+                            .map(|point| {
+                                ecc_chip.assign_point(ctx, Value::known(point.to_affine()))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+
+                        let sum = ecc_chip.add_many(ctx, &assigned)?;
+                        let folded = assigned
+                            .iter()
+                            .skip(1)
+                            .try_fold(assigned[0].clone(), |acc, point| {
+                                ecc_chip.add(ctx, &acc, point)
+                            })?;
+                        ecc_chip.assert_equal(ctx, &sum, &folded)?;
+
+                        let expected = points.iter().fold(C::Curve::identity(), |acc, p| acc + p);
+                        let expected =
+                            ecc_chip.assign_point(ctx, Value::known(expected.to_affine()))?;
+                        ecc_chip.assert_equal(ctx, &sum, &expected)?;
+                    }
+
+                    // repeated points and the identity are allowed with complete addition
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let p_assigned = &ecc_chip.assign_point(ctx, Value::known(p.to_affine()))?;
+                    let q_assigned = &ecc_chip.assign_point(ctx, Value::known(q.to_affine()))?;
+                    let p_assigned = ecc_chip.to_point_or_identity(ctx, p_assigned)?;
+                    let q_assigned = ecc_chip.to_point_or_identity(ctx, q_assigned)?;
+                    let identity = ecc_chip.assign_identity(ctx)?;
+                    let sum = ecc_chip.add_many_complete(
+                        ctx,
+                        &[p_assigned.clone(), identity, p_assigned, q_assigned],
+                    )?;
+                    main_gate.assert_zero(ctx, sum.is_identity())?;
+                    let expected =
+                        ecc_chip.assign_point(ctx, Value::known((p + p + q).to_affine()))?;
+                    ecc_chip.assert_equal(ctx, sum.point(), &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_add_many() {
+        run_ecc_test_circuit!(TestEccAddMany, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulComplete,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // small scalars select the identity entry of the table in
+                    // all windows but the last one
+                    for s in [
+                        C::Scalar::random(OsRng),
+                        C::Scalar::ONE,
+                        C::Scalar::from(2),
+                        C::Scalar::from((1 << self.window_size) - 1),
+                        -C::Scalar::ONE,
+                    ] {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let result_2 = ecc_chip.mul_complete(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_0, result_2.point())?;
+                        main_gate.assert_zero(ctx, result_2.is_identity())?;
+                    }
+
+                    let base = C::Curve::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let zero = ecc_chip.new_unassigned_scalar(Value::known(C::Scalar::ZERO));
+                    let zero = scalar_chip.assign_integer(ctx, zero, Range::Remainder)?;
+                    let result = ecc_chip.mul_complete(ctx, &base, &zero, self.window_size)?;
+                    main_gate.assert_one(ctx, result.is_identity())?;
+
+                    let table = ecc_chip.make_table_complete(ctx, &base, self.window_size)?;
+                    assert_eq!(table.len(), 1 << self.window_size);
+                    main_gate.assert_one(ctx, table[0].is_identity())?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_complete() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(
+                TestEccMulComplete,
+                window_size,
+                [Pallas, BnScalar],
+                [Secp256k1, PastaFp]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulConstantZero,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = C::Curve::random(OsRng);
+                    let point = ecc_chip.assign_point(ctx, Value::known(point.into()))?;
+
+                    let zero = scalar_chip.assign_constant(ctx, C::Scalar::ZERO)?;
+                    assert!(zero.is_known_zero());
+                    let offset = ctx.offset();
+                    let r = ecc_chip.mul_or_identity(ctx, &point, &zero, self.window_size)?;
+                    let constant_rows = ctx.offset() - offset;
+                    main_gate.assert_one(ctx, r.is_identity())?;
+
+                    let zero = ecc_chip.new_unassigned_scalar(Value::known(C::Scalar::ZERO));
+                    let zero = scalar_chip.assign_integer(ctx, zero, Range::Remainder)?;
+                    assert!(!zero.is_known_zero());
+                    let offset = ctx.offset();
+                    let r = ecc_chip.mul_or_identity(ctx, &point, &zero, self.window_size)?;
+                    let witness_rows = ctx.offset() - offset;
+                    main_gate.assert_one(ctx, r.is_identity())?;
+
+                    assert!(constant_rows * 100 < witness_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    impl_ecc_test_circuit!(
+        TestEccReduceToScalar,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use num_bigint::BigUint as big_uint;
+            use num_traits::One;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let base_chip = ecc_chip.base_field_chip();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = ecc_chip.rns_base().wrong_modulus.clone();
+                    let n = ecc_chip.rns_scalar().wrong_modulus.clone();
+                    // quotient is one for `p - 1` and `n` if `n < p`
+                    let mut values: Vec<big_uint> =
+                        (0..4).map(|_| fe_to_big(C::Base::random(OsRng))).collect();
+                    values.extend([big_uint::default(), &p - big_uint::one()]);
+                    if n < p {
+                        values.push(n.clone());
+                    }
+
+                    for value in values {
+                        let base_elem = Integer::from_big(value.clone(), ecc_chip.rns_base());
+                        let base_elem = base_chip.assign_integer(
+                            ctx,
+                            Value::known(base_elem).into(),
+                            Range::Remainder,
+                        )?;
+                        let scalar = ecc_chip.reduce_to_scalar(ctx, &base_elem)?;
+
+                        let expected = Integer::from_big(value % &n, ecc_chip.rns_scalar());
+                        let expected = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(expected).into(),
+                            Range::Remainder,
+                        )?;
+                        scalar_chip.assert_strict_equal(ctx, &scalar, &expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_reduce_to_scalar() {
+        run_ecc_test_circuit!(
+            TestEccReduceToScalar,
+            0,
+            [Pallas, BnScalar],
+            [Bn256, PastaFp],
+            [Secp256k1, BnScalar]
+        );
+    }
+
+    #[test]
+    fn test_general_ecc_mul_constant_zero() {
+        run_ecc_test_circuit!(
+            TestEccMulConstantZero,
+            4,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMissingAux,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            assert!(matches!(
+                ecc_chip.get_mul_aux(self.window_size, 1),
+                Err(Error::Synthesis)
+            ));
+
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+
+            assert!(ecc_chip.get_mul_aux(self.window_size, 1).is_ok());
+            assert!(matches!(
+                ecc_chip.get_mul_aux(self.window_size, 2),
+                Err(Error::Synthesis)
+            ));
+            assert!(matches!(
+                ecc_chip.get_mul_aux(self.window_size + 1, 1),
+                Err(Error::Synthesis)
+            ));
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_missing_aux() {
+        run_ecc_test_circuit!(
+            TestEccMissingAux,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSetupAuxGenerator,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let configs = [(self.window_size, 1), (self.window_size, 2)];
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // identity is rejected before anything is assigned
+                    let offset = ctx.offset();
+                    assert!(matches!(
+                        ecc_chip.setup_aux_generator(ctx, Value::known(C::identity()), &configs),
+                        Err(Error::Synthesis)
+                    ));
+                    assert_eq!(ctx.offset(), offset);
+                    assert!(ecc_chip.get_mul_aux(self.window_size, 1).is_err());
+
+                    ecc_chip.setup_aux_generator(ctx, Value::known(self.aux_generator), &configs)
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut pairs = vec![];
+                    let mut result = C::Curve::identity();
+                    for _ in 0..2 {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        result = result + base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        pairs.push((base, s));
+                    }
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let r_0 = ecc_chip.mul(ctx, &pairs[0].0, &pairs[0].1, self.window_size)?;
+                    let r_1 = ecc_chip.mul(ctx, &pairs[1].0, &pairs[1].1, self.window_size)?;
+                    let result_1 = ecc_chip.add(ctx, &r_0, &r_1)?;
+                    let result_2 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_setup_aux_generator() {
+        run_ecc_test_circuit!(
+            TestEccSetupAuxGenerator,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulWindowTooLarge,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = C::Scalar::random(OsRng);
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+
+                    let table = crate::Table(vec![base.clone()]);
+                    let bits = (0..usize::BITS)
+                        .map(|_| main_gate.assign_bit(ctx, Value::known(N::ZERO)))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    // rejected before anything is assigned, shifts would
+                    // overflow with windows near the bit width of `usize`
+                    let offset = ctx.offset();
+                    let bits_len = usize::BITS as usize;
+                    for window_size in [crate::MAX_WINDOW_SIZE + 1, bits_len - 1, bits_len] {
+                        assert!(matches!(
+                            ecc_chip.mul(ctx, &base, &s, window_size),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            ecc_chip.mul_with_table(ctx, &table, &s, window_size),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            ecc_chip.make_incremental_table(ctx, &base, &base, window_size),
+                            Err(Error::Synthesis)
+                        ));
+                        let selector = crate::Selector(bits[..window_size].to_vec());
+                        assert!(matches!(
+                            ecc_chip.select_multi(ctx, &selector, &table),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            ecc_chip.mul_batch_1d_horizontal(
+                                ctx,
+                                vec![(base.clone(), s.clone())],
+                                window_size
+                            ),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            ecc_chip.mul_naf(ctx, &base, &s, window_size),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            ecc_chip.mul_fixed_base(ctx, C::generator(), &s, window_size),
+                            Err(Error::Synthesis)
+                        ));
+                        assert!(matches!(
+                            GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::lookup_table_width(
+                                window_size
+                            ),
+                            Err(Error::Synthesis)
+                        ));
+                    }
+                    // zero window is rejected the same way
+                    assert!(matches!(
+                        ecc_chip.mul(ctx, &base, &s, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.mul_unpadded(ctx, &base, &s, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.decompose_scalar_into_windows(ctx, &s, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.mul_batch_1d_horizontal(ctx, vec![(base.clone(), s.clone())], 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.mul_naf(ctx, &base, &s, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        ecc_chip.mul_fixed_base(ctx, C::generator(), &s, 0),
+                        Err(Error::Synthesis)
+                    ));
+                    assert_eq!(offset, ctx.offset());
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_window_too_large() {
+        run_ecc_test_circuit!(
+            TestEccMulWindowTooLarge,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulRowEstimate,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let number_of_pairs = 3;
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                number_of_pairs,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let pairs = (0..number_of_pairs)
+                        .map(|_| {
+                            let base = C::Curve::random(OsRng);
+                            let s = C::Scalar::random(OsRng);
+                            let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            Ok((base, s))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let offset = ctx.offset();
+                    let (base, s) = &pairs[0];
+                    ecc_chip.mul(ctx, base, s, self.window_size)?;
+                    assert_eq!(
+                        ctx.offset() - offset,
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_row_estimate(
+                            self.window_size
+                        )?
+                    );
+
+                    let offset = ctx.offset();
+                    ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    assert_eq!(
+                        ctx.offset() - offset,
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_batch_row_estimate(
+                            number_of_pairs,
+                            self.window_size
+                        )?
+                    );
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_row_estimate() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccMulRowEstimate,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_row_estimate_invalid() {
+        type Chip = GeneralEccChip<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+        for window_size in [0, crate::MAX_WINDOW_SIZE + 1] {
+            assert!(matches!(
+                Chip::mul_row_estimate(window_size),
+                Err(Error::Synthesis)
+            ));
+            assert!(matches!(
+                Chip::mul_batch_row_estimate(2, window_size),
+                Err(Error::Synthesis)
+            ));
+        }
+        assert!(matches!(
+            Chip::mul_batch_row_estimate(0, 2),
+            Err(Error::Synthesis)
+        ));
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccOptimalWindowSize,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            for number_of_scalars in [1, 8] {
+                let optimal =
+                    GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::optimal_window_size(
+                        number_of_scalars,
+                    )?;
+                // rows grow with the table above the optimal window and with
+                // the ladder below it, so its neighbours are measured
+                let candidates: Vec<usize> =
+                    (optimal.max(2) - 1..=(optimal + 1).min(crate::MAX_WINDOW_SIZE)).collect();
+                for window_size in candidates.iter() {
+                    assign_aux(
+                        &mut ecc_chip,
+                        &mut layouter,
+                        self.aux_generator,
+                        *window_size,
+                        number_of_scalars,
+                    )?;
+                }
+
+                layouter.assign_region(
+                    || "region mul",
+                    |region| {
+                        let offset = 0;
+                        let ctx = &mut RegionCtx::new(region, offset);
+                        let scalar_chip = ecc_chip.scalar_field_chip();
+
+                        let pairs = (0..number_of_scalars)
+                            .map(|_| {
+                                let base = C::Curve::random(OsRng);
+                                let s = C::Scalar::random(OsRng);
+                                let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                                let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                                let s = scalar_chip.assign_integer(
+                                    ctx,
+                                    Value::known(s).into(),
+                                    Range::Remainder,
+                                )?;
+                                Ok((base, s))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+
+                        let mut rows = vec![];
+                        for window_size in candidates.iter() {
+                            let offset = ctx.offset();
+                            if number_of_scalars == 1 {
+                                let (base, s) = &pairs[0];
+                                ecc_chip.mul(ctx, base, s, *window_size)?;
+                            } else {
+                                ecc_chip.mul_batch_1d_horizontal(
+                                    ctx,
+                                    pairs.clone(),
+                                    *window_size,
+                                )?;
+                            }
+                            rows.push((ctx.offset() - offset, *window_size));
+                        }
+                        assert_eq!(rows.iter().min().unwrap().1, optimal);
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_optimal_window_size() {
+        run_ecc_test_circuit!(TestEccOptimalWindowSize, 1, [Pallas, BnScalar]);
+        type Chip = GeneralEccChip<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+        assert!(matches!(
+            Chip::optimal_window_size(0),
+            Err(Error::Synthesis)
+        ));
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulWithTable,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let assigned_base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let table = ecc_chip.precompute_table(ctx, &assigned_base, self.window_size)?;
+
+                    for _ in 0..2 {
+                        let s = C::Scalar::random(OsRng);
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = ecc_chip.mul(ctx, &assigned_base, &s, self.window_size)?;
+                        let result_2 =
+                            ecc_chip.mul_with_table(ctx, &table, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_with_table_circuit() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(TestEccMulWithTable, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulWithWindows,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let s = C::Scalar::random(OsRng);
+                    let s_assigned = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s_assigned = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s_assigned).into(),
+                        Range::Remainder,
+                    )?;
+                    let windowed = ecc_chip.decompose_scalar_into_windows(
+                        ctx,
+                        &s_assigned,
+                        self.window_size,
+                    )?;
+
+                    // same decomposition is used for multiple bases
+                    for _ in 0..2 {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = ecc_chip.mul(ctx, &base, &s_assigned, self.window_size)?;
+                        let result_2 =
+                            ecc_chip.mul_with_windows(ctx, &base, &windowed, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+
+                        // decomposition for another window size is rejected
+                        let windowed = ecc_chip.decompose_scalar_into_windows(
+                            ctx,
+                            &s_assigned,
+                            self.window_size + 1,
+                        )?;
+                        assert!(matches!(
+                            ecc_chip.mul_with_windows(ctx, &base, &windowed, self.window_size),
+                            Err(Error::Synthesis)
+                        ));
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_with_windows_circuit() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(TestEccMulWithWindows, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccWindowsFromBits,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let s = C::Scalar::random(OsRng);
+                    let s_assigned = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s_assigned = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s_assigned).into(),
+                        Range::Remainder,
+                    )?;
+
+                    let mut bits = scalar_chip.decompose(ctx, &s_assigned)?;
+                    ecc_chip.pad(ctx, &mut bits, self.window_size)?;
+                    let windowed = crate::windows_from_bits(bits, self.window_size);
+
+                    // last selector is the least significant window in little
+                    // endian order
+                    let selectors = windowed.selectors();
+                    let lsw: Value<u64> = selectors[selectors.len() - 1]
+                        .bits()
+                        .iter()
+                        .enumerate()
+                        .map(|(i, bit)| bit.value().map(|bit| ((*bit == N::ONE) as u64) << i))
+                        .fold(Value::known(0), |acc, bit| {
+                            acc.zip(bit).map(|(acc, bit)| acc + bit)
+                        });
+                    let mask = (1u64 << self.window_size) - 1;
+                    lsw.map(|lsw| {
+                        let expected = fe_to_big(s).iter_u64_digits().next().unwrap_or(0) & mask;
+                        assert_eq!(lsw, expected);
+                    });
+
+                    let base = C::Curve::random(OsRng);
+                    let result = base * s;
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_with_windows(ctx, &base, &windowed, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_windows_from_bits_circuit() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccWindowsFromBits, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulAdversarialScalars,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            // scalars with degenerate window patterns where doubling and
+            // addition inputs of the ladder are most likely to collide
+            let two = C::Scalar::from(2);
+            let two_pow = |k: usize| (0..k).fold(C::Scalar::ONE, |acc, _| acc * two);
+            let all_ones = -C::Scalar::ONE;
+            let max_window = (0..(C::Scalar::NUM_BITS as usize - 1) / self.window_size).fold(
+                C::Scalar::ZERO,
+                |acc, i| {
+                    acc + two_pow(i * self.window_size)
+                        * C::Scalar::from((1 << self.window_size) - 1)
+                },
+            );
+            let scalars = vec![
+                C::Scalar::ONE,
+                two,
+                -two,
+                all_ones,
+                two_pow(self.window_size),
+                two_pow(C::Scalar::NUM_BITS as usize - 1),
+                max_window,
+            ];
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let base_assigned = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    for s in scalars.iter() {
+                        let result =
+                            ecc_chip.assign_point(ctx, Value::known((base * *s).into()))?;
+                        let s = Integer::from_fe(*s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_1 = ecc_chip.mul(ctx, &base_assigned, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result, &result_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    impl_ecc_test_circuit!(
+        TestEccMulConstantTime,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            // scalars with the lowest and the highest hamming weight and
+            // zero windows or selector bits that are all set
+            let scalars = vec![
+                C::Scalar::random(OsRng),
+                C::Scalar::random(OsRng),
+                C::Scalar::ONE,
+                -C::Scalar::ONE,
+                C::Scalar::from(2).pow_vartime([C::Scalar::NUM_BITS as u64 - 1]),
+            ];
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut rows = vec![];
+                    for s in scalars.iter() {
+                        let base = C::Curve::random(OsRng);
+                        let result =
+                            ecc_chip.assign_point(ctx, Value::known((base * *s).into()))?;
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = Integer::from_fe(*s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+
+                        let offset = ctx.offset();
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        rows.push(ctx.offset() - offset);
+                        ecc_chip.assert_equal(ctx, &result, &result_1)?;
+                    }
+
+                    // same rows as with unknown witness values
+                    let expected =
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_row_estimate(
+                            self.window_size,
+                        )?;
+                    for rows in rows {
+                        assert_eq!(rows, expected);
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_constant_time() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccMulConstantTime,
+                window_size,
+                [Pallas, BnScalar],
+                [Secp256k1, PastaFp]
+            );
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_adversarial_scalars() {
+        // ladder inputs are also checked for exceptional cases in debug builds
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulAdversarialScalars, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccDebugValue,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng).to_affine();
+                    let s = C::Scalar::random(OsRng);
+                    let expected = (base * s).to_affine();
+
+                    let base_assigned = ecc_chip.assign_point(ctx, Value::known(base))?;
+                    assert_eq!(base_assigned.debug_value::<C>(), Some(base));
+                    let base = base_assigned;
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let result = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    assert_eq!(result.debug_value::<C>(), Some(expected));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_debug_value() {
+        run_ecc_test_circuit!(TestEccDebugValue, 4);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccPointValue,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = C::Curve::random(OsRng).to_affine();
+                    let assigned = ecc_chip.assign_point(ctx, Value::known(point))?;
+                    assert_eq!(ecc_chip.point_value(&assigned), Some(point));
+
+                    // coordinates off the curve
+                    let coords = point.coordinates().unwrap();
+                    let off_curve = ecc_chip.assign_point_unchecked(
+                        ctx,
+                        Value::known(*coords.x()),
+                        Value::known(*coords.y() + C::Base::ONE),
+                    )?;
+                    assert_eq!(ecc_chip.point_value(&off_curve), None);
+
+                    // native value of a coordinate that is not bound to its limbs
+                    let x = AssignedInteger::new(
+                        ecc_chip.rns_base(),
+                        assigned.x().limbs(),
+                        assigned.y().native().clone(),
+                    );
+                    let inconsistent = AssignedPoint::new(x, assigned.y().clone());
+                    assert_eq!(ecc_chip.point_value(&inconsistent), None);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_point_value() {
+        run_ecc_test_circuit!(TestEccPointValue, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccAssignPointRows,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = C::Curve::random(OsRng).to_affine();
+                    let coords = point.coordinates().unwrap();
+
+                    // a row per limb and a row for the native value of each
+                    // coordinate leave no room to share rows
+                    let offset = ctx.offset();
+                    let assigned = ecc_chip.assign_point_unchecked(
+                        ctx,
+                        Value::known(*coords.x()),
+                        Value::known(*coords.y()),
+                    )?;
+                    assert_eq!(ctx.offset() - offset, 2 * (NUMBER_OF_LIMBS + 1));
+                    assert_eq!(ecc_chip.point_value(&assigned), Some(point));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_assign_point_rows() {
+        run_ecc_test_circuit!(TestEccAssignPointRows, 0);
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulGlv<
+        C: HasEndomorphism,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        window_size: usize,
+        aux_generator: C,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: HasEndomorphism,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccMulGlv<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    ecc_chip.assign_aux_bounded(ctx, self.window_size, C::MAX_DECOMPOSED_BITS)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for s in [C::Scalar::random(OsRng), C::Scalar::ONE, -C::Scalar::ONE] {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let offset = ctx.offset();
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let mul_rows = ctx.offset() - offset;
+                        let offset = ctx.offset();
+                        let result_2 = ecc_chip.mul_glv(ctx, &base, &s, self.window_size)?;
+                        let glv_rows = ctx.offset() - offset;
+                        assert!(glv_rows < mul_rows);
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_glv() {
+        fn run<
+            C: HasEndomorphism,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            // endomorphism and decomposition are consistent
+            let point = C::Curve::random(OsRng).to_affine();
+            let coords = point.coordinates().unwrap();
+            let endo = C::from_xy(*coords.x() * C::beta(), *coords.y()).unwrap();
+            assert_eq!(endo, (point * C::lambda()).to_affine());
+            for _ in 0..100 {
+                let k = C::Scalar::random(OsRng);
+                let (k_1, k_2) = C::decompose_scalar(&k);
+                assert_eq!(k_1 + k_2 * C::lambda(), k);
+                for e in [k_1, k_2] {
+                    let e = fe_to_big(e).min(fe_to_big(-e));
+                    assert!(e.bits() <= 129);
+                }
+            }
+
+            for window_size in 1..5 {
+                let aux_generator = C::Curve::random(OsRng).to_affine();
+                let circuit = TestEccMulGlv::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    aux_generator,
+                    ..Default::default()
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+        }
+
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSelectBatch,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::{Selector, Table};
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let table_size = 1 << self.window_size;
+                    let points: Vec<C::Curve> =
+                        (0..table_size).map(|_| C::Curve::random(OsRng)).collect();
+                    let table = points
+                        .iter()
+                        .map(|point| ecc_chip.assign_point(ctx, Value::known(point.to_affine())))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let index = (OsRng.next_u32() as usize) % table_size;
+                    let selector = (0..self.window_size)
+                        .map(|i| {
+                            let bit = N::from(((index >> i) & 1) as u64);
+                            main_gate.assign_bit(ctx, Value::known(bit))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    // reference reduction selecting pair by pair
+                    let offset = ctx.offset();
+                    let mut reducer = table.clone();
+                    for (i, bit) in selector.iter().enumerate() {
+                        let n = 1 << (self.window_size - 1 - i);
+                        for j in 0..n {
+                            let k = 2 * j;
+                            reducer[j] = ecc_chip.select(ctx, bit, &reducer[k + 1], &reducer[k])?;
+                        }
+                    }
+                    let p_0 = reducer[0].clone();
+                    let pairwise_rows = ctx.offset() - offset;
+
+                    let table = Table(table);
+                    let selector = Selector(selector);
+                    let offset = ctx.offset();
+                    let p_1 = ecc_chip.select_multi(ctx, &selector, &table)?;
+                    let batch_rows = ctx.offset() - offset;
+
+                    // one main gate row per selected limb either way so
+                    // batching saves zero rows at window size 6
+                    assert_eq!(batch_rows, pairwise_rows);
+                    let expected =
+                        ecc_chip.assign_point(ctx, Value::known(points[index].to_affine()))?;
+                    ecc_chip.assert_equal(ctx, &p_0, &expected)?;
+                    ecc_chip.assert_equal(ctx, &p_1, &expected)?;
+
+                    // slices of different lengths are rejected
+                    let result =
+                        ecc_chip.select_batch(ctx, &selector.0[0], &table.0, &table.0[1..]);
+                    assert!(matches!(result, Err(Error::Synthesis)));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_select_batch() {
+        run_ecc_test_circuit!(TestEccSelectBatch, 6, [Pallas, BnScalar]);
+    }
+
+    #[cfg(feature = "dynamic-lookup")]
+    impl_ecc_test_circuit!(
+        TestEccSelectMultiLookup,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::{Selector, Table};
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let table_size = 1 << self.window_size;
+                    let points: Vec<C::Curve> =
+                        (0..table_size).map(|_| C::Curve::random(OsRng)).collect();
+                    let table = points
+                        .iter()
+                        .map(|point| ecc_chip.assign_point(ctx, Value::known(point.to_affine())))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let table = Table(table);
+                    let tag = N::ZERO;
+                    ecc_chip.load_lookup_table(ctx, &table, tag)?;
+
+                    for _ in 0..2 {
+                        let index = (OsRng.next_u32() as usize) % table_size;
+                        let selector = (0..self.window_size)
+                            .map(|i| {
+                                let bit = N::from(((index >> i) & 1) as u64);
+                                main_gate.assign_bit(ctx, Value::known(bit))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        let selector = Selector(selector);
+
+                        let offset = ctx.offset();
+                        let p_0 = ecc_chip.select_multi(ctx, &selector, &table)?;
+                        let binary_rows = ctx.offset() - offset;
+
+                        let offset = ctx.offset();
+                        let p_1 = ecc_chip.select_multi_lookup(ctx, &selector, &table, tag)?;
+                        let lookup_rows = ctx.offset() - offset;
+
+                        assert!(lookup_rows < binary_rows);
+                        let expected =
+                            ecc_chip.assign_point(ctx, Value::known(points[index].to_affine()))?;
+                        ecc_chip.assert_equal(ctx, &p_0, &expected)?;
+                        ecc_chip.assert_equal(ctx, &p_1, &expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[cfg(feature = "dynamic-lookup")]
+    #[test]
+    fn test_general_ecc_select_multi_lookup() {
+        for window_size in 4..=8 {
+            run_ecc_test_circuit!(TestEccSelectMultiLookup, window_size, [Pallas, BnScalar]);
+        }
+    }
+
+    #[cfg(feature = "dynamic-lookup")]
+    impl_ecc_test_circuit!(
+        TestEccMulLookup,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let width =
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::lookup_table_width(
+                            self.window_size,
+                        )?;
+                    for i in 0..2 {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        let result = base * s;
+
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        // each table is loaded to its own key range
+                        let tag = N::from((i * width) as u64);
+                        let result_1 =
+                            ecc_chip.mul_lookup(ctx, &base, &s, self.window_size, tag)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[cfg(feature = "dynamic-lookup")]
+    #[test]
+    fn test_general_ecc_mul_lookup_circuit() {
+        run_ecc_test_circuit!(
+            TestEccMulLookup,
+            4,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulNaf,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            layouter.assign_region(
+                || "assign naf aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_naf(ctx, self.window_size)
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // consecutive scalars to cover both odd and even cases
+                    let s = C::Scalar::random(OsRng);
+                    for s in [s, s + C::Scalar::ONE] {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                        let offset = ctx.offset();
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let mul_rows = ctx.offset() - offset;
+
+                        let offset = ctx.offset();
+                        let result_2 = ecc_chip.mul_naf(ctx, &base, &s, self.window_size)?;
+                        let naf_rows = ctx.offset() - offset;
+
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+                        // halved table pays off once selection dominates the cost of
+                        // conditional negation
+                        if self.window_size > 2 {
+                            assert!(naf_rows < mul_rows);
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_naf_circuit() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulNaf, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulBooth,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            layouter.assign_region(
+                || "assign booth aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_booth(ctx, self.window_size)
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // small scalars have zero digits and all ones scalar has
+                    // negative digits with carries in each window
+                    for s in [
+                        C::Scalar::random(OsRng),
+                        C::Scalar::ONE,
+                        C::Scalar::from((1 << self.window_size) - 1),
+                        -C::Scalar::ONE,
+                    ] {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        let result_2 = ecc_chip.mul_booth(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+                    }
+
+                    // table is about halved
+                    let aux = ecc_chip.get_mul_aux(self.window_size, 1)?;
+                    let base = C::Curve::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+
+                    let offset = ctx.offset();
+                    let table = ecc_chip.make_incremental_table(
+                        ctx,
+                        &aux.to_add,
+                        &base,
+                        self.window_size,
+                    )?;
+                    let table_rows = ctx.offset() - offset;
+                    assert_eq!(table.0.len(), 1 << self.window_size);
+
+                    let offset = ctx.offset();
+                    let table = ecc_chip.make_booth_table(ctx, &base, self.window_size)?;
+                    let booth_table_rows = ctx.offset() - offset;
+                    assert_eq!(table.0.len(), (1 << (self.window_size - 1)) + 1);
+                    if self.window_size > 1 {
+                        assert!(booth_table_rows < table_rows);
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_booth_circuit() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulBooth, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulStrategy,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::MulStrategy;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            layouter.assign_region(
+                || "assign signed aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_naf(ctx, self.window_size)?;
+                    ecc_chip.assign_aux_booth(ctx, self.window_size)
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let s = C::Scalar::random(OsRng);
+                    for s in [s, s + C::Scalar::ONE] {
+                        let base = C::Curve::random(OsRng);
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let expected = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                        let mut rows = vec![];
+                        for strategy in [
+                            MulStrategy::MinRows,
+                            MulStrategy::MinConstraints,
+                            MulStrategy::Balanced,
+                        ] {
+                            let offset = ctx.offset();
+                            let result = ecc_chip.mul_with_strategy(
+                                ctx,
+                                &base,
+                                &s,
+                                self.window_size,
+                                strategy,
+                            )?;
+                            rows.push(ctx.offset() - offset);
+                            ecc_chip.assert_equal(ctx, &result, &expected)?;
+                        }
+
+                        // balanced strategy is the default and lays out `mul`
+                        let offset = ctx.offset();
+                        ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        assert_eq!(MulStrategy::default(), MulStrategy::Balanced);
+                        assert_eq!(rows[2], ctx.offset() - offset);
+
+                        // offset deltas are `[min_rows, min_constraints, balanced]`
+                        // and `mul_naf` lays out the fewest rows above window
+                        // size two
+                        assert!(rows[0] < rows[1]);
+                        if self.window_size > 2 {
+                            assert!(rows[0] < rows[2]);
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_strategy() {
+        for window_size in 2..5 {
+            run_ecc_test_circuit!(TestEccMulStrategy, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccIncrementalTable,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+
+            layouter.assign_region(
+                || "region table",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = C::Curve::random(OsRng);
+                    let assigned = ecc_chip.assign_point(ctx, Value::known(point.into()))?;
+
+                    // entries are `aux + i * point` in order
+                    let aux = C::Curve::random(OsRng);
+                    let assigned_aux = ecc_chip.assign_point(ctx, Value::known(aux.into()))?;
+                    let table = ecc_chip.make_incremental_table(
+                        ctx,
+                        &assigned_aux,
+                        &assigned,
+                        self.window_size,
+                    )?;
+                    let expected: Vec<Option<C>> = (0..1u64 << self.window_size)
+                        .map(|i| Some((aux + point * C::Scalar::from(i)).to_affine()))
+                        .collect();
+                    assert_eq!(table.values::<C>(), expected);
+
+                    // precomputed table is built on top of the auxiliary generator
+                    let table = ecc_chip.precompute_table(ctx, &assigned, self.window_size)?;
+                    let values = table.values::<C>();
+                    assert_eq!(values.len(), 1 << self.window_size);
+                    assert_eq!(values[0], Some(self.aux_generator));
+                    assert_eq!(
+                        values[1],
+                        Some((self.aux_generator + point.to_affine()).to_affine())
+                    );
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_incremental_table() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(
+                TestEccIncrementalTable,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulFromBits,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+
+                    // bits of the full scalar decomposed outside of `mul`
+                    let s = C::Scalar::random(OsRng);
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let bits = scalar_chip.decompose(ctx, &s)?;
+                    let result = ecc_chip.mul_from_bits(ctx, &base, &bits, self.window_size)?;
+                    let expected = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    // short bits of a native value are extended with zeros
+                    let short = OsRng.next_u64();
+                    let value = main_gate.assign_value(ctx, Value::known(N::from(short)))?;
+                    let bits = main_gate.to_bits(ctx, &value, 64)?;
+                    let result = ecc_chip.mul_from_bits(ctx, &base, &bits, self.window_size)?;
+                    let s = Integer::from_fe(C::Scalar::from(short), ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let expected = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_from_bits() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccMulFromBits,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulSigned,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let k = C::Scalar::random(OsRng);
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let assign_scalar = |ctx: &mut RegionCtx<'_, N>, s: C::Scalar| {
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        scalar_chip.assign_integer(ctx, Value::known(s).into(), Range::Remainder)
+                    };
+                    let k_neg = assign_scalar(ctx, -k)?;
+                    let k = assign_scalar(ctx, k)?;
+
+                    // negative sign is the negated product
+                    let sign = main_gate.assign_bit(ctx, Value::known(N::ONE))?;
+                    let result = ecc_chip.mul_signed(ctx, &base, &sign, &k, self.window_size)?;
+                    let expected = ecc_chip.mul(ctx, &base, &k, self.window_size)?;
+                    let expected = ecc_chip.neg(ctx, &expected)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    // wrapped representative of `-k` gives the same point
+                    let wrapped = ecc_chip.mul(ctx, &base, &k_neg, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &wrapped, &expected)?;
+
+                    // positive sign is the product itself
+                    let sign = main_gate.assign_bit(ctx, Value::known(N::ZERO))?;
+                    let result = ecc_chip.mul_signed(ctx, &base, &sign, &k, self.window_size)?;
+                    let expected = ecc_chip.mul(ctx, &base, &k, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_signed() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccMulSigned,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulProfile,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::MulProfile;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let result = base * s;
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let expected = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let mut profile = MulProfile::default();
+                    let offset = ctx.offset();
+                    let result = ecc_chip.mul_with_profile(
+                        ctx,
+                        &base,
+                        &s,
+                        self.window_size,
+                        &mut profile,
+                    )?;
+                    let rows = ctx.offset() - offset;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+                    assert_eq!(profile.total(), rows);
+                    assert!(profile.decompose > 0);
+                    assert!(profile.make_incremental_table > 0);
+                    assert!(profile.select_multi > 0);
+                    assert!(profile.double_n > 0);
+                    assert!(profile.ladder > 0);
+                    assert!(profile.add > 0);
+
+                    // profiled layout is the layout of `mul`
+                    let offset = ctx.offset();
+                    ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    assert_eq!(ctx.offset() - offset, rows);
+
+                    // phases are accumulated across multiplications
+                    let first = profile.clone();
+                    ecc_chip.mul_with_profile(ctx, &base, &s, self.window_size, &mut profile)?;
+                    assert_eq!(profile.total(), 2 * rows);
+                    assert_eq!(profile.ladder, 2 * first.ladder);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_profile() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulProfile, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulWitness,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for s in [C::Scalar::random(OsRng), C::Scalar::ONE, -C::Scalar::ONE] {
+                        let base = C::Curve::random(OsRng).to_affine();
+                        let expected =
+                            GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_witness(
+                                self.aux_generator,
+                                base,
+                                s,
+                                self.window_size,
+                            );
+                        assert_eq!(expected, (base * s).to_affine());
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                        assert_eq!(result.debug_value::<C>(), Some(expected));
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_witness() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulWitness, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccSelectMultiSigned,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::{Selector, SignedSelector, Table};
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let window_size = self.window_size;
+                    let table_size = 1 << window_size;
+                    let entries: Vec<C> = (0..table_size)
+                        .map(|_| C::Curve::random(OsRng).to_affine())
+                        .collect();
+                    let table = Table(
+                        entries
+                            .iter()
+                            .map(|entry| ecc_chip.assign_point(ctx, Value::known(*entry)))
+                            .collect::<Result<Vec<_>, Error>>()?,
+                    );
+
+                    for (index, entry) in entries.iter().enumerate() {
+                        // little endian bits of the index
+                        let bits = (0..window_size)
+                            .map(|i| {
+                                let bit = if (index >> i) & 1 == 1 {
+                                    N::ONE
+                                } else {
+                                    N::ZERO
+                                };
+                                main_gate.assign_bit(ctx, Value::known(bit))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+
+                        // unsigned path
+                        let offset = ctx.offset();
+                        let unsigned =
+                            ecc_chip.select_multi(ctx, &Selector::new(bits.clone()), &table)?;
+                        let unsigned_rows = ctx.offset() - offset;
+                        let expected = ecc_chip.assign_point(ctx, Value::known(*entry))?;
+                        ecc_chip.assert_equal(ctx, &unsigned, &expected)?;
+
+                        for negative in [false, true] {
+                            let sign = if negative { N::ONE } else { N::ZERO };
+                            let sign = main_gate.assign_bit(ctx, Value::known(sign))?;
+                            let selector = SignedSelector::new(Selector::new(bits.clone()), sign);
+
+                            let offset = ctx.offset();
+                            let signed = ecc_chip.select_multi_signed(ctx, &selector, &table)?;
+                            let signed_rows = ctx.offset() - offset;
+
+                            // absolute value is selected exactly as in the unsigned path
+                            let offset = ctx.offset();
+                            let negated = ecc_chip.neg(ctx, &unsigned)?;
+                            ecc_chip.select(ctx, selector.sign(), &negated, &unsigned)?;
+                            assert_eq!(signed_rows, unsigned_rows + ctx.offset() - offset);
+
+                            let expected = if negative { -*entry } else { *entry };
+                            let expected = ecc_chip.assign_point(ctx, Value::known(expected))?;
+                            ecc_chip.assert_equal(ctx, &signed, &expected)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_select_multi_signed() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(TestEccSelectMultiSigned, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulDouble,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 2)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p_1 = C::Curve::random(OsRng);
+                    let p_2 = C::Curve::random(OsRng);
+                    let s_1 = C::Scalar::random(OsRng);
+                    let s_2 = C::Scalar::random(OsRng);
+                    let result = p_1 * s_1 + p_2 * s_2;
+
+                    let p_1 = ecc_chip.assign_point(ctx, Value::known(p_1.into()))?;
+                    let p_2 = ecc_chip.assign_point(ctx, Value::known(p_2.into()))?;
+                    let s_1 = Integer::from_fe(s_1, ecc_chip.rns_scalar());
+                    let s_2 = Integer::from_fe(s_2, ecc_chip.rns_scalar());
+                    let s_1 = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s_1).into(),
+                        Range::Remainder,
+                    )?;
+                    let s_2 = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s_2).into(),
+                        Range::Remainder,
+                    )?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let r_1 = ecc_chip.mul(ctx, &p_1, &s_1, self.window_size)?;
+                    let r_2 = ecc_chip.mul(ctx, &p_2, &s_2, self.window_size)?;
+                    let result_1 = ecc_chip.add(ctx, &r_1, &r_2)?;
+
+                    let offset = ctx.offset();
+                    let result_2 =
+                        ecc_chip.mul_double(ctx, &p_1, &s_1, &p_2, &s_2, self.window_size)?;
+                    let double_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let pairs = vec![(p_1, s_1), (p_2, s_2)];
+                    let result_3 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    let batch_rows = ctx.offset() - offset;
+
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_2)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_3)?;
+                    if self.window_size == 1 {
+                        assert!(double_rows < batch_rows);
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_double_circuit() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(TestEccMulDouble, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulFixedBase,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng).to_affine();
+                    let s = C::Scalar::random(OsRng);
+                    let result = base * s;
+
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let base_assigned = ecc_chip.assign_point(ctx, Value::known(base))?;
+                    let result_1 = ecc_chip.mul(ctx, &base_assigned, &s, self.window_size)?;
+                    let result_2 = ecc_chip.mul_fixed_base(ctx, base, &s, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_fixed_base_circuit() {
+        for window_size in 1..5 {
+            run_ecc_test_circuit!(TestEccMulFixedBase, window_size);
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_fixed_base_offset() {
+        type Chip = GeneralEccChip<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+        // offsets are constants of the circuit so they must be reproducible
+        let offset = Chip::fixed_base_offset_at(0);
+        assert_eq!(offset, Chip::fixed_base_offset_at(0));
+        assert_eq!(
+            offset,
+            aux_generator_from_tag::<Secp256k1>(b"fixed-base-offset-0").to_curve()
+        );
+        assert_ne!(offset, Chip::fixed_base_offset_at(1));
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulFixedBaseBatch,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng).to_affine();
+                    let number_of_scalars = 3;
+                    let mut scalars = vec![];
+                    let mut expected = vec![];
+                    for _ in 0..number_of_scalars {
+                        let s = C::Scalar::random(OsRng);
+                        let result = (base * s).to_affine();
+                        expected.push(ecc_chip.assign_point(ctx, Value::known(result))?);
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        scalars.push(scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?);
+                    }
+
+                    let offset = ctx.offset();
+                    let results =
+                        ecc_chip.mul_fixed_base_batch(ctx, base, &scalars, self.window_size)?;
+                    let batch_rows = ctx.offset() - offset;
+                    assert_eq!(results.len(), number_of_scalars);
+
+                    let offset = ctx.offset();
+                    for ((s, result), expected) in
+                        scalars.iter().zip(results.iter()).zip(expected.iter())
+                    {
+                        let single = ecc_chip.mul_fixed_base(ctx, base, s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, result, expected)?;
+                        ecc_chip.assert_equal(ctx, &single, expected)?;
+                    }
+                    let separate_rows = ctx.offset() - offset;
+                    // tables are assigned once in the batch
+                    assert!(batch_rows < separate_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_fixed_base_batch_circuit() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(TestEccMulFixedBaseBatch, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMsmMixed,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let number_of_variable = 2;
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, number_of_variable)?;
+                    // for single multiplications of the reference
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let assign_scalar = |ctx: &mut RegionCtx<'_, N>| {
+                        let s = Integer::from_fe(C::Scalar::random(OsRng), ecc_chip.rns_scalar());
+                        scalar_chip.assign_integer(ctx, Value::known(s).into(), Range::Remainder)
+                    };
+
+                    let fixed = (0..2)
+                        .map(|_| Ok((C::Curve::random(OsRng).to_affine(), assign_scalar(ctx)?)))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let variable = (0..number_of_variable)
+                        .map(|_| {
+                            let point = C::Curve::random(OsRng).to_affine();
+                            let point = ecc_chip.assign_point(ctx, Value::known(point))?;
+                            Ok((point, assign_scalar(ctx)?))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    // sum of individual multiplications
+                    let mut terms = vec![];
+                    for (point, scalar) in fixed.iter() {
+                        terms.push(ecc_chip.mul_fixed_base(
+                            ctx,
+                            *point,
+                            scalar,
+                            self.window_size,
+                        )?);
+                    }
+                    for (point, scalar) in variable.iter() {
+                        terms.push(ecc_chip.mul(ctx, point, scalar, self.window_size)?);
+                    }
+
+                    let result = ecc_chip.msm_mixed(
+                        ctx,
+                        fixed.clone(),
+                        variable.clone(),
+                        self.window_size,
+                    )?;
+                    let expected = ecc_chip.add_many(ctx, &terms)?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    // either of the lists can be empty
+                    let result = ecc_chip.msm_mixed(ctx, fixed, vec![], self.window_size)?;
+                    let expected = ecc_chip.add(ctx, &terms[0], &terms[1])?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    let result = ecc_chip.msm_mixed(ctx, vec![], variable, self.window_size)?;
+                    let expected = ecc_chip.add(ctx, &terms[2], &terms[3])?;
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    assert!(ecc_chip
+                        .msm_mixed(ctx, vec![], vec![], self.window_size)
+                        .is_err());
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_msm_mixed() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(
+                TestEccMsmMixed,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccIsEqual,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+            let base_chip = ecc_chip.base_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = C::Curve::random(OsRng).to_affine();
+                    let coords = a.coordinates().unwrap();
+                    let p0 = ecc_chip.assign_point(ctx, Value::known(a))?;
+
+                    // equal points
+                    let p1 = ecc_chip.assign_point(ctx, Value::known(a))?;
+                    let is_equal = ecc_chip.is_equal(ctx, &p0, &p1)?;
+                    main_gate.assert_one(ctx, &is_equal)?;
+                    ecc_chip.assert_equal(ctx, &p0, &p1)?;
+
+                    // points differing only in x
+                    let x = ecc_chip.new_unassigned_base(Value::known(*coords.x() + C::Base::ONE));
+                    let x = base_chip.assign_integer(ctx, x, Range::Remainder)?;
+                    let p1 = AssignedPoint::new(x, p0.y().clone());
+                    let is_equal = ecc_chip.is_equal(ctx, &p0, &p1)?;
+                    main_gate.assert_zero(ctx, &is_equal)?;
+
+                    // points differing only in y
+                    let y = ecc_chip.new_unassigned_base(Value::known(*coords.y() + C::Base::ONE));
+                    let y = base_chip.assign_integer(ctx, y, Range::Remainder)?;
+                    let p1 = AssignedPoint::new(p0.x().clone(), y);
+                    let is_equal = ecc_chip.is_equal(ctx, &p0, &p1)?;
+                    main_gate.assert_zero(ctx, &is_equal)?;
+
+                    // negation differs only in y
+                    let p1 = ecc_chip.neg(ctx, &p0)?;
+                    let is_equal = ecc_chip.is_equal(ctx, &p0, &p1)?;
+                    main_gate.assert_zero(ctx, &is_equal)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_is_equal_circuit() {
+        run_ecc_test_circuit!(TestEccIsEqual, 0);
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccDoubleNZero,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let p = &ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+
+                    let offset = ctx.offset();
+                    let q = &ecc_chip.double_n(ctx, p, 0)?;
+                    assert_eq!(offset, ctx.offset());
+                    assert!(p.shares_cells(q));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_double_n_zero() {
+        run_ecc_test_circuit!(
+            TestEccDoubleNZero,
+            0,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccOnCurve<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        x: C::Base,
+        y: C::Base,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccOnCurve<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let base_chip = ecc_chip.base_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let x = ecc_chip.new_unassigned_base(Value::known(self.x));
+                    let y = ecc_chip.new_unassigned_base(Value::known(self.y));
+                    let x = base_chip.assign_integer(ctx, x, Range::Remainder)?;
+                    let y = base_chip.assign_integer(ctx, y, Range::Remainder)?;
+                    let point = AssignedPoint::new(x, y);
+                    ecc_chip.assert_is_on_curve(ctx, &point)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_on_curve() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let point = C::Curve::random(OsRng).to_affine();
+            let coords = point.coordinates().unwrap();
+            let (x, y) = (*coords.x(), *coords.y());
+
+            let circuit = TestEccOnCurve::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y,
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+
+            let circuit = TestEccOnCurve::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y: y + C::Base::ONE,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[test]
+    fn test_general_ecc_on_curve_nonzero_a() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            assert!(!bool::from(C::a().is_zero()));
+
+            // find a point of `y^2 = x^3 + b` which is not on the curve
+            let (x, y) = loop {
+                let x = C::Base::random(OsRng);
+                let y = (x.square() * x + C::b()).sqrt();
+                if bool::from(y.is_some()) {
+                    break (x, y.unwrap());
+                }
+            };
+            let circuit = TestEccOnCurve::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccAssertXEquals<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        point: C,
+        r: C::Scalar,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccAssertXEquals<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = ecc_chip.assign_point(ctx, Value::known(self.point))?;
+                    let r = ecc_chip.new_unassigned_scalar(Value::known(self.r));
+                    let r = scalar_chip.assign_integer(ctx, r, Range::Remainder)?;
+                    ecc_chip.assert_x_equals(ctx, &point, &r)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_assert_x_equals() {
+        use maingate::big_to_fe;
+        use num_bigint::BigUint as big_uint;
+
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >(
+            point: C,
+            r: C::Scalar,
+        ) {
+            let circuit = TestEccAssertXEquals::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point,
+                r,
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+
+            let circuit = TestEccAssertXEquals::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point,
+                r: r + C::Scalar::ONE,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        // `r` of the signature with the nonce `k = 1` is the `x` coordinate of
+        // the generator of secp256k1
+        let r = big_uint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16,
+        )
+        .unwrap();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(
+            Secp256k1::generator(),
+            big_to_fe(r),
+        );
+
+        fn run_with_nonce<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let k = C::Scalar::random(OsRng);
+            let point = (C::generator() * k).to_affine();
+            let x = fe_to_big(*point.coordinates().unwrap().x());
+            let n = fe_to_big(-C::Scalar::ONE) + 1usize;
+            run::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(point, big_to_fe(x % n));
+        }
+
+        run_with_nonce::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run_with_nonce::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run_with_nonce::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccInSubgroup<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        x: C::Base,
+        y: C::Base,
+        aux_generator: C,
+        window_size: usize,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccInSubgroup<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = ecc_chip.assign_point_unchecked(
+                        ctx,
+                        Value::known(self.x),
+                        Value::known(self.y),
+                    )?;
+                    ecc_chip.assert_in_subgroup(ctx, &point, self.window_size)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_in_subgroup() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let window_size = 2;
+            let aux_generator = C::Curve::random(OsRng).to_affine();
+            let point = C::Curve::random(OsRng).to_affine();
+            let coords = point.coordinates().unwrap();
+            let (x, y) = (*coords.x(), *coords.y());
+
+            let circuit = TestEccInSubgroup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y,
+                aux_generator,
+                window_size,
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+
+            // Curves under test have cofactor one so there is no point on the
+            // curve outside of the subgroup. Only an unchecked point off the
+            // curve is rejected here.
+            //
+            // TODO: reject an on curve point outside of the subgroup once the
+            // pinned halo2curves provides a curve with cofactor greater than
+            // one over a prime base field
+            let circuit = TestEccInSubgroup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y: y + C::Base::ONE,
+                aux_generator,
+                window_size,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulBounded<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        window_size: usize,
+        aux_generator: C,
+        max_bits: usize,
+        scalar: C::Scalar,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccMulBounded<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_bounded(ctx, self.window_size, self.max_bits)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let result = base * self.scalar;
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = Integer::from_fe(self.scalar, ecc_chip.rns_scalar());
+                    let s =
+                        scalar_chip.assign_integer(ctx, Value::known(s).into(), Range::Remainder)?;
+
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let offset = ctx.offset();
+                    let result_1 =
+                        ecc_chip.mul_bounded(ctx, &base, &s, self.max_bits, self.window_size)?;
+                    let bounded_rows = ctx.offset() - offset;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    assert!(
+                        bounded_rows
+                            < GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_row_estimate(
+                                self.window_size
+                            )?
+                    );
+
+                    // limbs that don't fit in a limb length are reduced first
+                    let zero = Integer::from_fe(C::Scalar::ZERO, ecc_chip.rns_scalar());
+                    let zero =
+                        scalar_chip.assign_integer(ctx, Value::known(zero).into(), Range::Remainder)?;
+                    let s = scalar_chip.sub(ctx, &s, &zero)?;
+                    assert!(!s.has_reduced_limbs());
+                    let result_1 =
+                        ecc_chip.mul_bounded(ctx, &base, &s, self.max_bits, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    for max_bits in [C::Scalar::NUM_BITS as usize + 1, self.window_size] {
+                        assert!(matches!(
+                            ecc_chip.mul_bounded(ctx, &base, &s, max_bits, self.window_size),
+                            Err(Error::Synthesis)
+                        ));
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_bounded() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let max_bits = 128;
+            // 128 is not a multiple of 3
+            for window_size in [3, 4] {
+                let aux_generator = C::Curve::random(OsRng).to_affine();
+                let scalar = (u128::from(OsRng.next_u64()) << 64) | u128::from(OsRng.next_u64());
+                let circuit = TestEccMulBounded::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    aux_generator,
+                    max_bits,
+                    scalar: C::Scalar::from_u128(scalar),
+                    ..Default::default()
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+
+                // 2^128 doesn't fit
+                let circuit = TestEccMulBounded::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    aux_generator,
+                    max_bits,
+                    scalar: C::Scalar::from_u128(u128::MAX) + C::Scalar::ONE,
+                    ..Default::default()
+                };
+                mock_prover_verify_fails(&circuit, vec![vec![]]);
+            }
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMulNativeScalar,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::{big_to_fe, MainGateInstructions};
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_bounded(ctx, self.window_size, N::NUM_BITS as usize)?;
+                    Ok(())
+                },
+            )?;
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // largest native value is included
+                    for s in [N::random(OsRng), -N::ONE, N::ONE] {
+                        let base = C::Curve::random(OsRng);
+                        // reference multiplication with the scalar reduced
+                        // modulo the order of the curve
+                        let result = base * big_to_fe::<C::Scalar>(fe_to_big(s));
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = main_gate.assign_value(ctx, Value::known(s))?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                        let result_1 =
+                            ecc_chip.mul_native_scalar(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_native_scalar() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccMulNativeScalar,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccAssignPointUnchecked<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        x: C::Base,
+        y: C::Base,
+        checked: bool,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccAssignPointUnchecked<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let integer_chip = ecc_chip.base_field_chip();
+                    let offset = ctx.offset();
+                    integer_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_base(Value::known(self.x)),
+                        Range::Remainder,
+                    )?;
+                    let coordinate_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let point = ecc_chip.assign_point_unchecked(
+                        ctx,
+                        Value::known(self.x),
+                        Value::known(self.y),
+                    )?;
+                    // no row is shared between range checks of coordinates
+                    assert_eq!(ctx.offset() - offset, 2 * coordinate_rows);
+                    // `assign_point` is the unchecked assignment followed by
+                    // the curve equation
+                    if self.checked {
+                        ecc_chip.assert_is_on_curve(ctx, &point)?;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_assign_point_unchecked() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let point = C::Curve::random(OsRng).to_affine();
+            let coords = point.coordinates().unwrap();
+            let (x, y) = (*coords.x(), *coords.y() + C::Base::ONE);
+
+            let circuit = TestEccAssignPointUnchecked::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y,
+                checked: false,
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+
+            let circuit = TestEccAssignPointUnchecked::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                y,
+                checked: true,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccFromCompressed<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        x: C::Base,
+        sign: bool,
+        expected: Option<C>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccFromCompressed<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+            let base_chip = ecc_chip.base_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let x = ecc_chip.new_unassigned_base(Value::known(self.x));
+                    let x = &base_chip.assign_integer(ctx, x, Range::Remainder)?;
+                    let sign = if self.sign { N::ONE } else { N::ZERO };
+                    let sign = &main_gate.assign_bit(ctx, Value::known(sign))?;
+                    let point = &ecc_chip.from_compressed(ctx, x, sign)?;
+
+                    if let Some(expected) = self.expected {
+                        let expected = &ecc_chip.assign_point(ctx, Value::known(expected))?;
+                        ecc_chip.assert_equal(ctx, point, expected)?;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_from_compressed() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for _ in 0..2 {
+                let point = C::Curve::random(OsRng).to_affine();
+                let coords = point.coordinates().unwrap();
+                let circuit = TestEccFromCompressed::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    x: *coords.x(),
+                    sign: fe_to_big(*coords.y()).bit(0),
+                    expected: Some(point),
+                    ..Default::default()
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+
+            // find an `x` such that `x^3 + ax + b` is not a square
+            let x = loop {
+                let x = C::Base::random(OsRng);
+                let rhs = x.square() * x + C::a() * x + C::b();
+                if bool::from(rhs.sqrt().is_none()) {
+                    break x;
+                }
+            };
+            let circuit = TestEccFromCompressed::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                x,
+                ..Default::default()
+            };
+            mock_prover_verify_fails(&circuit, vec![vec![]]);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    /// Out of circuit counterpart of `map_to_curve`
+    fn map_to_curve_reference<C: CurveAffine>(u: C::Base) -> C {
+        for i in 0..crate::MAP_TO_CURVE_ATTEMPTS {
+            let x = u + C::Base::from(i as u64);
+            let rhs = x.square() * x + C::a() * x + C::b();
+            if let Some(y) = Option::<C::Base>::from(rhs.sqrt()) {
+                let y = if fe_to_big(y).bit(0) { -y } else { y };
+                return C::from_xy(x, y).unwrap();
+            }
+        }
+        panic!("no point is found");
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMapToCurve,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let base_chip = ecc_chip.base_field_chip();
+
+            // first candidate is not on the curve for the last input
+            let mut inputs: Vec<C::Base> = (0..2).map(|_| C::Base::random(OsRng)).collect();
+            inputs.push(loop {
+                let u = C::Base::random(OsRng);
+                let rhs = u.square() * u + C::a() * u + C::b();
+                if bool::from(rhs.sqrt().is_none()) {
+                    break u;
+                }
+            });
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for u in inputs.iter() {
+                        let expected = map_to_curve_reference::<C>(*u);
+                        let expected = ecc_chip.assign_point(ctx, Value::known(expected))?;
+
+                        let u = Integer::from_fe(*u, ecc_chip.rns_base());
+                        let u = base_chip.assign_integer(
+                            ctx,
+                            Value::known(u).into(),
+                            Range::Remainder,
+                        )?;
+                        let point = ecc_chip.map_to_curve(ctx, &u)?;
+                        ecc_chip.assert_equal(ctx, &point, &expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_map_to_curve() {
+        run_ecc_test_circuit!(
+            TestEccMapToCurve,
+            0,
+            [Pallas, BnScalar],
+            [Bn256, PastaFp],
+            [Secp256k1, PastaFq],
+            [Secp256r1, BnScalar]
+        );
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccBatchMul<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        window_size: usize,
+        aux_generator: C,
+        number_of_pairs: usize,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccBatchMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        #[allow(clippy::type_complexity)]
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, self.number_of_pairs)?;
+                    ecc_chip.get_mul_aux(self.window_size, self.number_of_pairs)?;
+                    Ok(())
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut acc = C::Curve::identity();
+                    let pairs: Vec<(
+                        AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                        AssignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                    )> = (0..self.number_of_pairs)
+                        .map(|_| {
+                            let base = C::Curve::random(OsRng);
+                            let s = C::Scalar::random(OsRng);
+                            acc += base * s;
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            Ok((base, s))
+                        })
+                        .collect::<Result<_, Error>>()?;
+
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulWindowMismatch,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                2,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let aux = ecc_chip.get_mul_aux(self.window_size, 2)?;
+
+                    let mut tables = vec![];
+                    let mut windowed_scalars = vec![];
+                    for _ in 0..2 {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let decomposed = &mut scalar_chip.decompose(ctx, &s)?;
+                        ecc_chip.pad(ctx, decomposed, self.window_size)?;
+                        windowed_scalars.push(
+                            GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::window(
+                                decomposed.to_vec(),
+                                self.window_size,
+                            ),
+                        );
+                        tables.push(ecc_chip.make_incremental_table(
+                            ctx,
+                            &aux.to_add,
+                            &base,
+                            self.window_size,
+                        )?);
+                    }
+
+                    // drop a window from the second decomposition
+                    windowed_scalars[1].0.pop();
+                    let result = ecc_chip.mul_batch_windowed(
+                        ctx,
+                        &tables,
+                        &windowed_scalars,
+                        &aux.to_sub,
+                        self.window_size,
+                        "mul_batch_windowed",
+                    );
+                    assert!(matches!(result, Err(Error::Synthesis)));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_window_mismatch() {
+        run_ecc_test_circuit!(
+            TestEccBatchMulWindowMismatch,
+            2,
+            [Pallas, BnScalar],
+            [Bn256, BnScalar],
+            [Secp256k1, BnScalar]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulMaxRows,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let number_of_pairs = 2;
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                number_of_pairs,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut acc = C::Curve::identity();
+                    let mut pairs = vec![];
+                    for _ in 0..number_of_pairs {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        acc += base * s;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        pairs.push((base, s));
+                    }
+                    let expected = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+
+                    let estimate =
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::mul_batch_row_estimate(
+                            number_of_pairs,
+                            self.window_size,
+                        )?;
+                    let exceeded = |result: Result<_, Error>| {
+                        result.err().as_ref().and_then(RowLimitExceeded::from_error)
+                    };
+                    let operation = "mul_batch_1d_horizontal";
+                    let margin = 10;
+                    let mut limited = ecc_chip.clone();
+
+                    // batch stops at the first table that goes past the limit
+                    let offset = ctx.offset();
+                    limited.set_max_rows(Some(offset + margin));
+                    let result =
+                        limited.mul_batch_1d_horizontal(ctx, pairs.clone(), self.window_size);
+                    assert!(ctx.offset() - offset < estimate);
+                    assert_eq!(
+                        exceeded(result),
+                        Some(RowLimitExceeded {
+                            operation,
+                            overflow: ctx.offset() - offset - margin,
+                        })
+                    );
+
+                    // batch that goes a single row past the limit
+                    let offset = ctx.offset();
+                    limited.set_max_rows(Some(offset + estimate - 1));
+                    let result =
+                        limited.mul_batch_1d_horizontal(ctx, pairs.clone(), self.window_size);
+                    assert_eq!(
+                        exceeded(result),
+                        Some(RowLimitExceeded {
+                            operation,
+                            overflow: 1,
+                        })
+                    );
+
+                    // batch that exactly fits
+                    let offset = ctx.offset();
+                    limited.set_max_rows(Some(offset + estimate));
+                    let result = limited.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    assert_eq!(ctx.offset() - offset, estimate);
+                    ecc_chip.assert_equal(ctx, &result, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_max_rows() {
+        run_ecc_test_circuit!(
+            TestEccBatchMulMaxRows,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulBinaryAux,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::{Selector, Windowed};
+            let number_of_pairs = 3;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                number_of_pairs,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let aux = ecc_chip.get_mul_aux(self.window_size, number_of_pairs)?;
+
+                    let mut acc = C::Curve::identity();
+                    let mut pairs = vec![];
+                    for _ in 0..number_of_pairs {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        acc += base * s;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let windowed =
+                            ecc_chip.decompose_scalar_into_windows(ctx, &s, self.window_size)?;
+                        pairs.push((base, windowed));
+                    }
+                    let expected = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+
+                    // tables built while doubling the auxiliary point in turn
+                    let offset = ctx.offset();
+                    let mut binary_aux = aux.to_add.clone();
+                    let mut tables = vec![];
+                    for (i, (point, _)) in pairs.iter().enumerate() {
+                        tables.push(ecc_chip.make_incremental_table(
+                            ctx,
+                            &binary_aux,
+                            point,
+                            self.window_size,
+                        )?);
+                        if i != pairs.len() - 1 {
+                            binary_aux = ecc_chip.double(ctx, &binary_aux)?;
+                        }
+                    }
+                    let windowed_scalars: Vec<_> = pairs
+                        .iter()
+                        .map(|(_, windowed)| {
+                            Windowed(
+                                windowed
+                                    .0
+                                    .iter()
+                                    .map(|selector| Selector(selector.0.clone()))
+                                    .collect(),
+                            )
+                        })
+                        .collect();
+                    let result_0 = ecc_chip.mul_batch_windowed(
+                        ctx,
+                        &tables,
+                        &windowed_scalars,
+                        &aux.to_sub,
+                        self.window_size,
+                        "mul_batch_windowed",
+                    )?;
+                    let interleaved_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let result_1 = ecc_chip.mul_batch_with_windows(ctx, pairs, self.window_size)?;
+                    let rows = ctx.offset() - offset;
+
+                    assert_eq!(rows, interleaved_rows);
+                    ecc_chip.assert_equal(ctx, &result_0, &expected)?;
+                    ecc_chip.assert_equal(ctx, &result_1, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_binary_aux() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(
+                TestEccBatchMulBinaryAux,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccMsmStreaming,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let number_of_pairs = 3;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                number_of_pairs,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut acc = C::Curve::identity();
+                    let mut pairs = vec![];
+                    for _ in 0..number_of_pairs {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        acc += base * s;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        pairs.push((base, s));
+                    }
+                    let expected = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+
+                    // Batch keeps `number_of_pairs` tables of `2^window_size`
+                    // points alive through the whole ladder while streaming
+                    // keeps a single one and drops it after the pair is
+                    // accumulated. In exchange doublings are repeated for
+                    // every pair.
+                    let offset = ctx.offset();
+                    let result_0 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs.clone(), self.window_size)?;
+                    let batch_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let result_1 = ecc_chip.msm_streaming(ctx, pairs, self.window_size)?;
+                    let streaming_rows = ctx.offset() - offset;
+
+                    ecc_chip.assert_equal(ctx, &result_0, &expected)?;
+                    ecc_chip.assert_equal(ctx, &result_1, &expected)?;
+                    assert!(batch_rows < streaming_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_msm_streaming() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(
+                TestEccMsmStreaming,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, PastaFp],
+                [Secp256k1, PastaFq]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulEmpty,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let offset = ctx.offset();
+                    assert!(matches!(
+                        ecc_chip.mul_batch_1d_horizontal(ctx, vec![], self.window_size),
+                        Err(Error::Synthesis)
+                    ));
+                    assert_eq!(ctx.offset(), offset);
+
+                    // only the identity is assigned
+                    let offset = ctx.offset();
+                    ecc_chip.assign_identity(ctx)?;
+                    let identity_rows = ctx.offset() - offset;
+                    let offset = ctx.offset();
+                    let result = ecc_chip.mul_batch_or_identity(ctx, vec![], self.window_size)?;
+                    assert_eq!(ctx.offset() - offset, identity_rows);
+                    main_gate.assert_one(ctx, result.is_identity())?;
+
+                    let base = C::Curve::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let expected = ecc_chip.assign_point(ctx, Value::known((base * s).into()))?;
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let result =
+                        ecc_chip.mul_batch_or_identity(ctx, vec![(base, s)], self.window_size)?;
+                    main_gate.assert_zero(ctx, result.is_identity())?;
+                    ecc_chip.assert_equal(ctx, result.point(), &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_empty() {
+        run_ecc_test_circuit!(
+            TestEccBatchMulEmpty,
+            2,
+            [Pallas, BnScalar],
+            [Secp256k1, PastaFp]
+        );
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulWithWindows,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let number_of_pairs = 3;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                number_of_pairs,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut acc = C::Curve::identity();
+                    let mut pairs = vec![];
+                    let mut pairs_with_windows = vec![];
+                    for _ in 0..number_of_pairs {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        acc += base * s;
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let windowed =
+                            ecc_chip.decompose_scalar_into_windows(ctx, &s, self.window_size)?;
+                        pairs.push((base.clone(), s));
+                        pairs_with_windows.push((base, windowed));
+                    }
+
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(acc.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs.clone(), self.window_size)?;
+                    let result_2 = ecc_chip.mul_batch_with_windows(
+                        ctx,
+                        pairs_with_windows,
+                        self.window_size,
+                    )?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result_1, &result_2)?;
+
+                    // drop a window from one of the decompositions
+                    let mut pairs_with_windows = pairs
+                        .iter()
+                        .map(|(base, s)| {
+                            let windowed =
+                                ecc_chip.decompose_scalar_into_windows(ctx, s, self.window_size)?;
+                            Ok((base.clone(), windowed))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    pairs_with_windows[1].1 .0.pop();
+                    let result =
+                        ecc_chip.mul_batch_with_windows(ctx, pairs_with_windows, self.window_size);
+                    assert!(matches!(result, Err(Error::Synthesis)));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_with_windows() {
+        for window_size in 1..4 {
+            run_ecc_test_circuit!(
+                TestEccBatchMulWithWindows,
+                window_size,
+                [Pallas, BnScalar],
+                [Secp256k1, PastaFp]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccPadShared,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let number_of_scalars = 10;
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region pad",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let decomposed_scalars = (0..number_of_scalars)
+                        .map(|_| {
+                            let s = C::Scalar::random(OsRng);
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            scalar_chip.decompose(ctx, &s)
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let offset = ctx.offset();
+                    let mut padded_0 = decomposed_scalars.clone();
+                    for decomposed in padded_0.iter_mut() {
+                        ecc_chip.pad(ctx, decomposed, self.window_size)?;
+                    }
+                    let rows_0 = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let mut padded_1 = decomposed_scalars;
+                    let zero = ecc_chip.padding_zero(ctx, self.window_size)?;
+                    for decomposed in padded_1.iter_mut() {
+                        GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::pad_with(
+                            decomposed,
+                            zero.as_ref(),
+                            self.window_size,
+                        );
+                    }
+                    let rows_1 = ctx.offset() - offset;
+
+                    // a single zero is assigned for the whole batch
+                    let number_of_bits = C::Scalar::NUM_BITS as usize;
+                    let padding_rows = usize::from(number_of_bits % self.window_size != 0);
+                    assert_eq!(rows_0, number_of_scalars * padding_rows);
+                    assert_eq!(rows_1, padding_rows);
+
+                    for (bits_0, bits_1) in padded_0.iter().zip(padded_1.iter()) {
+                        assert_eq!(bits_0.len(), bits_1.len());
+                        for (bit_0, bit_1) in bits_0.iter().zip(bits_1.iter()) {
+                            main_gate.assert_equal(ctx, bit_0, bit_1)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_pad_shared() {
+        for window_size in 2..5 {
+            run_ecc_test_circuit!(
+                TestEccPadShared,
+                window_size,
+                [Pallas, BnScalar],
+                [Bn256, BnScalar],
+                [Secp256k1, PastaFp]
+            );
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulDuplicatePoints,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 2)?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 3)?;
+                    Ok(())
+                },
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let scalars: Vec<C::Scalar> =
+                        (0..3).map(|_| C::Scalar::random(OsRng)).collect();
+                    let result = p * scalars[0] + p * scalars[1] + q * scalars[2];
+                    let result = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let scalars = scalars
+                        .into_iter()
+                        .map(|s| {
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let p_0 = ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let p_1 = ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let q = ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+
+                    // same point used twice
+                    let pairs = vec![
+                        (p_0.clone(), scalars[0].clone()),
+                        (p_0.clone(), scalars[1].clone()),
+                        (q.clone(), scalars[2].clone()),
+                    ];
+                    let offset = ctx.offset();
+                    let result_0 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    let merged_rows = ctx.offset() - offset;
+
+                    // same value assigned twice is treated independently
+                    let pairs = vec![
+                        (p_0, scalars[0].clone()),
+                        (p_1, scalars[1].clone()),
+                        (q, scalars[2].clone()),
+                    ];
+                    let offset = ctx.offset();
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    let independent_rows = ctx.offset() - offset;
+
+                    ecc_chip.assert_equal(ctx, &result, &result_0)?;
+                    ecc_chip.assert_equal(ctx, &result, &result_1)?;
+                    assert!(merged_rows < independent_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_duplicate_points() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(TestEccBatchMulDuplicatePoints, window_size);
+        }
+    }
+
+    impl_ecc_test_circuit!(
+        TestEccBatchMulDuplicatePointsUnmerged,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            // auxiliary point only for the number of pairs
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                3,
+            )?;
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = C::Curve::random(OsRng);
+                    let q = C::Curve::random(OsRng);
+                    let scalars: Vec<C::Scalar> =
+                        (0..3).map(|_| C::Scalar::random(OsRng)).collect();
+                    let result = p * scalars[0] + p * scalars[1] + q * scalars[2];
+                    let result = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let scalars = scalars
+                        .into_iter()
+                        .map(|s| {
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let p_0 = ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let p_1 = ecc_chip.assign_point(ctx, Value::known(p.into()))?;
+                    let q = ecc_chip.assign_point(ctx, Value::known(q.into()))?;
+
+                    // same point used twice while there is no auxiliary point
+                    // for two distinct points
+                    let pairs = vec![
+                        (p_0.clone(), scalars[0].clone()),
+                        (p_0.clone(), scalars[1].clone()),
+                        (q.clone(), scalars[2].clone()),
+                    ];
+                    let offset = ctx.offset();
+                    let result_0 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs.clone(), self.window_size)?;
+                    let unmerged_rows = ctx.offset() - offset;
+                    let result_1 = ecc_chip.msm_streaming(ctx, pairs.clone(), self.window_size)?;
+                    let result_2 = ecc_chip.msm_mixed(ctx, vec![], pairs, self.window_size)?;
+
+                    let pairs = vec![
+                        (p_0, scalars[0].clone()),
+                        (p_1, scalars[1].clone()),
+                        (q, scalars[2].clone()),
+                    ];
+                    let offset = ctx.offset();
+                    let result_3 =
+                        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    let independent_rows = ctx.offset() - offset;
+
+                    ecc_chip.assert_equal(ctx, &result, &result_0)?;
+                    ecc_chip.assert_equal(ctx, &result, &result_1)?;
+                    ecc_chip.assert_equal(ctx, &result, &result_2)?;
+                    ecc_chip.assert_equal(ctx, &result, &result_3)?;
+                    // pairs are laid out one table per pair as if points
+                    // were distinct
+                    assert_eq!(unmerged_rows, independent_rows);
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    );
+
+    #[test]
+    fn test_general_ecc_mul_batch_duplicate_points_unmerged() {
+        for window_size in 1..3 {
+            run_ecc_test_circuit!(TestEccBatchMulDuplicatePointsUnmerged, window_size);
+        }
+    }
+
+    macro_rules! test_general_ecc_mul_batch_circuit {
+        ($C:ty, $N:ty, $NUMBER_OF_LIMBS:expr, $BIT_LEN_LIMB:expr) => {
+            paste! {
+                #[test]
+                fn [<test_general_ecc_mul_batch_circuit_ $C:lower _ $N:lower>]() {
+                    for number_of_pairs in 5..7 {
+                        for window_size in 1..3 {
+                            let aux_generator = <$C as PrimeCurveAffine>::Curve::random(OsRng).to_affine();
+
+                            let circuit = TestEccBatchMul::<$C, $N, $NUMBER_OF_LIMBS, $BIT_LEN_LIMB> {
+                                aux_generator,
+                                window_size,
+                                number_of_pairs,
+                                ..Default::default()
+                            };
+                            let instance = vec![vec![]];
+                            mock_prover_verify(&circuit, instance);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    test_general_ecc_mul_batch_circuit!(Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    test_general_ecc_mul_batch_circuit!(Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    test_general_ecc_mul_batch_circuit!(Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    test_general_ecc_mul_batch_circuit!(Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    /// Scalars checked by the property test. Small scalars and scalars near
+    /// `n - 1` leave most windows of the decomposition zero or full, random
+    /// scalars cover the rest.
+    fn property_scalars<C: CurveAffine>(window_size: usize, rounds: usize) -> Vec<C::Scalar> {
+        let one = C::Scalar::ONE;
+        let window = C::Scalar::from(1 << window_size);
+        let mut scalars = vec![
+            one,
+            one + one,
+            C::Scalar::from(3),
+            window - one,
+            window,
+            -one,
+            -one - one,
+        ];
+        scalars.extend((0..rounds).map(|_| C::Scalar::random(OsRng)));
+        scalars
+    }
+
+    /// Property test circuit that runs `add`, `double`, `ladder`, `mul` and
+    /// `mul_batch_1d_horizontal` over `(point, other, scalar)` cases and
+    /// compares results with the arithmetic of the `group` crate. Witnesses
+    /// are read back with `point_value` so that a mismatch panics with both
+    /// points and results are also constrained to be equal to the reference.
+    #[derive(Default, Clone, Debug)]
+    struct TestEccProperty<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        cases: Vec<(C, C, C::Scalar)>,
+        window_size: usize,
+        aux_generator: C,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > TestEccProperty<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        fn new(window_size: usize, rounds: usize) -> Self {
+            let cases = property_scalars::<C>(window_size, rounds)
+                .into_iter()
+                .map(|scalar| {
+                    let point = C::Curve::random(OsRng).to_affine();
+                    let other = C::Curve::random(OsRng).to_affine();
+                    (point, other, scalar)
+                })
+                .collect();
+            Self {
+                cases,
+                window_size,
+                aux_generator: C::Curve::random(OsRng).to_affine(),
+                _marker: PhantomData,
+            }
+        }
+
+        fn check(
+            ecc_chip: &GeneralEccChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            ctx: &mut RegionCtx<'_, N>,
+            operation: &str,
+            result: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            expected: C,
+        ) -> Result<(), Error> {
+            if let Some(value) = ecc_chip.point_value(result) {
+                assert_eq!(value, expected, "{} differs from the reference", operation);
+            }
+            let expected = ecc_chip.assign_point(ctx, Value::known(expected))?;
+            ecc_chip.assert_equal(ctx, result, &expected)
+        }
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccProperty<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let number_of_pairs = self.cases.len();
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.setup_aux_generator(
+                        ctx,
+                        Value::known(self.aux_generator),
+                        &[(self.window_size, 1), (self.window_size, number_of_pairs)],
+                    )
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            layouter.assign_region(
+                || "region property",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut pairs = vec![];
+                    let mut sum = C::Curve::identity();
+                    for (point, other, scalar) in self.cases.iter() {
+                        let (point, other, scalar) = (*point, *other, *scalar);
+                        let point_assigned = ecc_chip.assign_point(ctx, Value::known(point))?;
+                        let other_assigned = ecc_chip.assign_point(ctx, Value::known(other))?;
+                        let scalar_assigned = scalar_chip.assign_integer(
+                            ctx,
+                            ecc_chip.new_unassigned_scalar(Value::known(scalar)),
+                            Range::Remainder,
+                        )?;
+
+                        let r = ecc_chip.add(ctx, &point_assigned, &other_assigned)?;
+                        Self::check(&ecc_chip, ctx, "add", &r, (point + other).into())?;
+                        let r = ecc_chip.double(ctx, &point_assigned)?;
+                        let expected = point.to_curve().double().to_affine();
+                        Self::check(&ecc_chip, ctx, "double", &r, expected)?;
+                        let r = ecc_chip.ladder(ctx, &point_assigned, &other_assigned)?;
+                        Self::check(&ecc_chip, ctx, "ladder", &r, (point + other + point).into())?;
+                        let r = ecc_chip.mul(
+                            ctx,
+                            &point_assigned,
+                            &scalar_assigned,
+                            self.window_size,
+                        )?;
+                        Self::check(&ecc_chip, ctx, "mul", &r, (point * scalar).into())?;
+
+                        sum += point * scalar;
+                        pairs.push((point_assigned, scalar_assigned));
+                    }
+
+                    let r = ecc_chip.mul_batch_1d_horizontal(ctx, pairs, self.window_size)?;
+                    Self::check(&ecc_chip, ctx, "mul_batch_1d_horizontal", &r, sum.into())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    /// Multiplication where `point * scalar` cancels the auxiliary
+    /// contribution. The accumulator ends at `point * scalar - to_sub` and
+    /// the correction `to_sub` is added last, so the scalar zero and `point *
+    /// scalar = 2 * to_sub` are the boundary cases where both operands share
+    /// the `x` coordinate. `mul` cannot add them so `mul_or_identity` is
+    /// checked against `expected`, `None` meaning the identity.
+    #[derive(Default, Clone, Debug)]
+    struct TestEccAuxBoundary<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        point: C,
+        scalar: C::Scalar,
+        expected: Option<C>,
+        window_size: usize,
+        aux_generator: C,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccAuxBoundary<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use maingate::MainGateInstructions;
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            assign_aux(
+                &mut ecc_chip,
+                &mut layouter,
+                self.aux_generator,
+                self.window_size,
+                1,
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+            layouter.assign_region(
+                || "region boundary",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let point = ecc_chip.assign_point(ctx, Value::known(self.point))?;
+                    let scalar = scalar_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_scalar(Value::known(self.scalar)),
+                        Range::Remainder,
+                    )?;
+
+                    let r = ecc_chip.mul_or_identity(ctx, &point, &scalar, self.window_size)?;
+                    match self.expected {
+                        Some(expected) => {
+                            main_gate.assert_zero(ctx, r.is_identity())?;
+                            let expected = ecc_chip.assign_point(ctx, Value::known(expected))?;
+                            ecc_chip.assert_equal(ctx, r.point(), &expected)
+                        }
+                        None => main_gate.assert_one(ctx, r.is_identity()),
+                    }
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_property() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for window_size in 1..4 {
+                let circuit =
+                    TestEccProperty::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(window_size, 4);
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+
+            let window_size = 2;
+            let aux_generator = C::Curve::random(OsRng).to_affine();
+            let to_sub = super::make_mul_aux(aux_generator, window_size, 1);
+            let boundary = to_sub.to_curve().double();
+            let scalar = C::Scalar::random(OsRng);
+            let point = (boundary * scalar.invert().unwrap()).to_affine();
+            for (point, scalar, expected) in [
+                (point, C::Scalar::ZERO, None),
+                (point, scalar, Some(boundary.to_affine())),
+            ] {
+                let circuit = TestEccAuxBoundary::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    point,
+                    scalar,
+                    expected,
+                    window_size,
+                    aux_generator,
+                    _marker: PhantomData,
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
 }
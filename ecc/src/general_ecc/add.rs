@@ -3,6 +3,8 @@ use super::GeneralEccChip;
 use crate::halo2;
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::PrimeField;
+#[cfg(debug_assertions)]
+use halo2::halo2curves::group::{prime::PrimeCurveAffine, Group};
 use halo2::plonk::Error;
 use integer::maingate::RegionCtx;
 use integer::IntegerInstructions;
@@ -56,9 +58,14 @@ impl<
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let ch = self.base_field_chip();
 
-        // lambda = (3 * a_x^2) / 2 * a_y
+        // lambda = (3 * a_x^2 + a) / 2 * a_y
         let x_0_square = &ch.square(ctx, &point.x)?;
         let numerator = &ch.mul3(ctx, x_0_square)?;
+        let numerator = &if Self::has_parameter_a() {
+            ch.add_constant(ctx, numerator, &self.parameter_a())?
+        } else {
+            numerator.clone()
+        };
         let denominator = &ch.mul2(ctx, &point.y)?;
         let lambda = &ch.div_incomplete(ctx, numerator, denominator)?;
 
@@ -74,6 +81,24 @@ impl<
         Ok(AssignedPoint::new(x.clone(), y))
     }
 
+    /// Samples the witness in debug builds to catch the exceptional cases of
+    /// `_ladder_incomplete` during synthesis rather than as an unsatisfied
+    /// constraint. Inputs that are not on the curve are not checked.
+    #[cfg(debug_assertions)]
+    pub(crate) fn debug_assert_ladder_inputs(
+        &self,
+        to_double: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        to_add: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) {
+        let p = to_double.debug_value::<Emulated>();
+        let q = to_add.debug_value::<Emulated>();
+        if let (Some(p), Some(q)) = (p, q) {
+            debug_assert!(p != q && p != -q, "ladder: Q = P or Q = -P");
+            let r = p.to_curve().double() + q.to_curve();
+            debug_assert!(!bool::from(r.is_identity()), "ladder: Q = -2P");
+        }
+    }
+
     /// Given 2 `AssignedPoint` $P$ and $Q$ efficiently computes $2*P + Q$
     ///
     /// see: https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
@@ -0,0 +1,135 @@
+use super::{AssignedPoint, GeneralEccChip};
+use crate::integer::{AssignedInteger, IntegerInstructions};
+use crate::maingate::{AssignedCondition, MainGateInstructions};
+use crate::{check_window_size, halo2, Selector, SignedSelector, Table};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::PrimeField;
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Constructs table of multiples of the point
+    /// `[P, P, 2P, ..., 2^(window_size - 1)P]` to be used in Booth recoded
+    /// multiplication. Entry at index zero stands for the zero digit and is
+    /// never accumulated.
+    pub(super) fn make_booth_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let table_size = (1 << (window_size - 1)) + 1;
+        let mut table = vec![point.clone(), point.clone()];
+        if table_size > 2 {
+            table.push(self.double(region, point)?);
+            for i in 3..table_size {
+                table.push(self.add(region, &table[i - 1], point)?);
+            }
+        }
+        Ok(Table(table))
+    }
+
+    /// Selects the signed multiple of a point for a Booth digit. Digit is
+    /// found from bits of the window in little endian order and the most
+    /// significant bit of the previous window as the carry. Returns the
+    /// selected point and a condition that is zero if the digit is zero.
+    fn select_booth(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        window: &[AssignedCondition<N>],
+        carry: Option<&AssignedCondition<N>>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let main_gate = self.main_gate();
+        let sign = &window[window.len() - 1];
+
+        // absolute value of the digit is `lower + carry` for a positive digit
+        // and `!lower + !carry` for a negative one
+        let lower = window[..window.len() - 1]
+            .iter()
+            .map(|bit| main_gate.xor(region, bit, sign))
+            .collect::<Result<Vec<AssignedCondition<N>>, Error>>()?;
+        let carry = match carry {
+            Some(carry) => main_gate.xor(region, carry, sign)?,
+            None => sign.clone(),
+        };
+
+        // entries are shifted by one if carry is set
+        let shifted = (0..table.0.len() - 1)
+            .map(|i| self.select(region, &carry, &table.0[i + 1], &table.0[i]))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let selector = SignedSelector::new(Selector(lower.clone()), sign.clone());
+        let selected = self.select_multi_signed(region, &selector, &Table(shifted))?;
+        let is_nonzero = lower
+            .iter()
+            .try_fold(carry, |acc, bit| main_gate.or(region, &acc, bit))?;
+
+        Ok((selected, is_nonzero))
+    }
+
+    /// Scalar multiplication of a point in the EC
+    /// Performed with radix `2^window_size` Booth recoding.
+    ///
+    /// Little endian bits `b_i` of the scalar are split into windows and each
+    /// window `j` is recoded into the signed digit
+    /// `d_j = b_{jw-1} + sum_{i < w-1} b_{jw+i} 2^i - b_{jw+w-1} 2^(w-1)`
+    /// in `[-2^(w-1), 2^(w-1)]` where the most significant bit of the previous
+    /// window is carried as `b_{jw-1}`. Scalar is padded with at least one
+    /// zero so that the carry of the most significant bit is absorbed by an
+    /// additional window. Only `2^(w-1) + 1` entries are required in the
+    /// table where `make_incremental_table` builds `2^w`. Selected point is
+    /// conditionally negated with the sign of the digit and zero digits are
+    /// skipped.
+    ///
+    /// Since auxiliary generator is added only once, `assign_aux_booth` must be
+    /// called for the `window_size` before using this function.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_booth(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let aux = self.get_mul_aux_booth(window_size)?;
+
+        let main_gate = self.main_gate();
+        let scalar_chip = self.scalar_field_chip();
+        // little endian bits where the most significant bit is a padding zero
+        let mut decomposed = scalar_chip.decompose(region, scalar)?;
+        let number_of_windows = decomposed.len() / window_size + 1;
+        let zero = main_gate.assign_constant(region, N::ZERO)?;
+        decomposed.resize(number_of_windows * window_size, zero);
+
+        let table = &self.make_booth_table(region, point, window_size)?;
+
+        let mut acc = aux.to_add.clone();
+        for i in (0..number_of_windows).rev() {
+            if i != number_of_windows - 1 {
+                acc = self.double_n(region, &acc, window_size)?;
+            }
+            let window = &decomposed[i * window_size..(i + 1) * window_size];
+            let carry = (i > 0).then(|| &decomposed[i * window_size - 1]);
+            let (to_add, is_nonzero) = self.select_booth(region, window, carry, table)?;
+            let added = self.add(region, &acc, &to_add)?;
+            acc = self.select(region, &is_nonzero, &added, &acc)?;
+        }
+
+        self.add(region, &acc, &aux.to_sub)
+    }
+}
@@ -0,0 +1,251 @@
+use super::{AssignedPoint, GeneralEccChip};
+use crate::integer::{AssignedInteger, IntegerInstructions};
+use crate::maingate::{AssignedCondition, MainGateInstructions};
+use crate::{check_window_size, halo2, AssignedPointOrIdentity, Selector};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::{ff::PrimeField, group::prime::PrimeCurveAffine};
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Lifts an `AssignedPoint` to an `AssignedPointOrIdentity` that is not
+    /// the identity
+    pub fn to_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let is_identity = self.main_gate().assign_constant(ctx, N::ZERO)?;
+        Ok(AssignedPointOrIdentity::new(p.clone(), is_identity))
+    }
+
+    /// Assigns the identity. Generator of the curve is used as the
+    /// placeholder coordinates.
+    pub fn assign_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let point = self.assign_constant(ctx, Emulated::generator())?;
+        let is_identity = self.main_gate().assign_constant(ctx, N::ONE)?;
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
+    /// Complete point addition that handles `P + P`, `P + (-P)` and the
+    /// identity operands. Much more expensive than `add` so prefer it only
+    /// where exceptional inputs cannot be ruled out. Curve is assumed to
+    /// have no point of order two.
+    pub fn add_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let main_gate = self.main_gate();
+        let ch = self.base_field_chip();
+        let (a, b) = (p.point(), q.point());
+
+        // lambda = b_y - a_y / b_x - a_x
+        // Division doesn't fail if `a_x = b_x` and `x_equal` is set instead
+        let numerator = &ch.sub(ctx, &b.y, &a.y)?;
+        let denominator = &ch.sub(ctx, &b.x, &a.x)?;
+        let (lambda, x_equal) = ch.div(ctx, numerator, denominator)?;
+        let (_, y_equal) = ch.invert(ctx, numerator)?;
+        let sum = self.add_with_lambda(ctx, a, b, &lambda)?;
+
+        // Coordinates are always valid points so doubling never fails
+        let double = self._double_incomplete(ctx, a)?;
+
+        // x_equal & y_equal: a + a = 2a
+        // x_equal & !y_equal: a - a = identity where coordinates of `a` are kept
+        let r = &self.select(ctx, &y_equal, &double, a)?;
+        let r = &self.select(ctx, &x_equal, r, &sum)?;
+        let not_y_equal = &main_gate.not(ctx, &y_equal)?;
+        let is_identity = &main_gate.and(ctx, &x_equal, not_y_equal)?;
+
+        // Identity operands
+        let r = &self.select(ctx, q.is_identity(), a, r)?;
+        let r = self.select(ctx, p.is_identity(), b, r)?;
+        let is_identity = &main_gate.select(ctx, p.is_identity(), is_identity, q.is_identity())?;
+        let is_identity = main_gate.select(ctx, q.is_identity(), is_identity, p.is_identity())?;
+
+        Ok(AssignedPointOrIdentity::new(r, is_identity))
+    }
+
+    /// Doubles an `AssignedPointOrIdentity` where doubling the identity
+    /// returns the identity. Coordinates of the identity are always a point
+    /// on the curve, see `assign_identity`, so they are doubled as `double`
+    /// does and only the identity flag is carried over. Curve is assumed to
+    /// have no point of order two.
+    pub fn double_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let point = self._double_incomplete(ctx, p.point())?;
+        Ok(AssignedPointOrIdentity::new(point, p.is_identity().clone()))
+    }
+
+    /// Sums all points with `add_complete` in a binary tree as `add_many`
+    /// does. Any of the points can be the identity or equal to another one.
+    ///
+    /// Returns `Error::Synthesis` if no point is given.
+    pub fn add_many_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        points: &[AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        if points.is_empty() {
+            return Err(Error::Synthesis);
+        }
+        let mut layer = points.to_vec();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => self.add_complete(ctx, a, b),
+                    [a] => Ok(a.clone()),
+                    _ => unreachable!(),
+                })
+                .collect::<Result<_, Error>>()?;
+        }
+        Ok(layer.pop().unwrap())
+    }
+
+    /// Selects `p` if the condition is set and `q` otherwise
+    fn select_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let point = self.select(ctx, c, p.point(), q.point())?;
+        let is_identity = self
+            .main_gate()
+            .select(ctx, p.is_identity(), q.is_identity(), c)?;
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
+    /// Returns `p` if the condition is set and the identity otherwise.
+    /// Coordinates of `p` are kept in both cases so the result is well
+    /// formed for `add_complete` and the identity is absorbed there.
+    pub fn select_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let is_identity = self.main_gate().not(ctx, c)?;
+        Ok(AssignedPointOrIdentity::new(p.clone(), is_identity))
+    }
+
+    /// Constructs table of multiples of the point `[O, P, 2P, ..., (2^window_size - 1)P]`
+    /// starting from the identity. Unlike `make_incremental_table` no
+    /// auxiliary point is required since entries are found with complete
+    /// addition.
+    pub fn make_table_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Vec<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error>
+    {
+        check_window_size(window_size)?;
+        let table_size = 1 << window_size;
+        let point = self.to_point_or_identity(ctx, point)?;
+        let mut table = vec![self.assign_identity(ctx)?, point.clone()];
+        for i in 2..table_size {
+            table.push(self.add_complete(ctx, &table[i - 1], &point)?);
+        }
+        Ok(table)
+    }
+
+    /// Selects an entry of the table built with `make_table_complete` where
+    /// the first selector is the least significant bit of the index
+    fn select_multi_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        selector: &Selector<N>,
+        table: &[AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let number_of_selectors = selector.0.len();
+        check_window_size(number_of_selectors)?;
+        assert!(table.len() >= 1 << number_of_selectors);
+
+        let mut reducer = table[..1 << number_of_selectors].to_vec();
+        for (i, selector) in selector.0.iter().enumerate() {
+            let n = 1 << (number_of_selectors - 1 - i);
+            for j in 0..n {
+                let k = 2 * j;
+                reducer[j] = self.select_complete(ctx, selector, &reducer[k + 1], &reducer[k])?;
+            }
+        }
+        Ok(reducer[0].clone())
+    }
+
+    /// Scalar multiplication of a point in the EC with complete addition.
+    /// Accumulator starts from the identity so that neither the auxiliary
+    /// point nor the final correction is required and any scalar including
+    /// zero is handled. Every doubling and addition is complete so this is
+    /// much more expensive than `mul`.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        check_window_size(window_size)?;
+        let table = &self.make_table_complete(ctx, point, window_size)?;
+        let windowed = self.decompose_scalar_into_windows(ctx, scalar, window_size)?;
+
+        let mut acc = self.select_multi_complete(ctx, &windowed.0[0], table)?;
+        for selector in windowed.0.iter().skip(1) {
+            for _ in 0..window_size {
+                acc = self.double_complete(ctx, &acc)?;
+            }
+            let to_add = self.select_multi_complete(ctx, selector, table)?;
+            acc = self.add_complete(ctx, &acc, &to_add)?;
+        }
+        Ok(acc)
+    }
+
+    /// Computes `a + b` given the slope of the line passing through them
+    fn add_with_lambda(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        lambda: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.base_field_chip();
+
+        // c_x =  lambda * lambda - a_x - b_x
+        let lambda_square = &ch.square(ctx, lambda)?;
+        let x = ch.sub_sub(ctx, lambda_square, &a.x, &b.x)?;
+
+        // c_y = lambda * (a_x - c_x) - a_y
+        let t = &ch.sub(ctx, &a.x, &x)?;
+        let t = &ch.mul(ctx, t, lambda)?;
+        let y = ch.sub(ctx, t, &a.y)?;
+
+        Ok(AssignedPoint::new(x, y))
+    }
+}
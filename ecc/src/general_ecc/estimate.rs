@@ -0,0 +1,554 @@
+use super::GeneralEccChip;
+use crate::integer::rns::{Integer, Rns};
+use crate::integer::NUMBER_OF_LOOKUP_LIMBS;
+use crate::maingate::{compose, fe_to_big};
+use crate::{check_window_size, halo2, MAX_WINDOW_SIZE};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::PrimeField;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+use std::rc::Rc;
+
+/// Number of terms a main gate row takes besides the cell that carries the
+/// sum to the next row
+const TERMS_PER_ROW: usize = 4;
+
+/// Rows of `compose` with the given number of non zero terms
+fn compose_rows(number_of_terms: usize) -> usize {
+    (number_of_terms - 1) / TERMS_PER_ROW + 1
+}
+
+/// Rows of `assert_zero_sum` with the given number of non zero terms. First
+/// row takes one more term since no sum is carried into it.
+fn assert_zero_sum_rows(number_of_terms: usize) -> usize {
+    if number_of_terms <= TERMS_PER_ROW + 1 {
+        1
+    } else {
+        1 + compose_rows(number_of_terms - TERMS_PER_ROW - 1)
+    }
+}
+
+/// Maximum values of limbs of an integer as the integer chip tracks them
+type MaxVals = Vec<big_uint>;
+
+/// Maximum values of limbs of point coordinates
+#[derive(Clone)]
+struct PointMax {
+    x: MaxVals,
+    y: MaxVals,
+}
+
+/// Counts rows that the integer chip lays out. Maximum values of limbs are
+/// followed as the chip follows them so that reductions applied only after
+/// limbs grow large enough are counted too.
+struct IntegerRows<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    rows: usize,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerRows<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn new(rns: Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self {
+            rns: Rc::new(rns),
+            rows: 0,
+        }
+    }
+
+    /// Rows of a value range checked with the range chip
+    fn range_rows(bit_len: usize) -> usize {
+        let sublimb_bit_len = BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS;
+        compose_rows((bit_len + sublimb_bit_len - 1) / sublimb_bit_len)
+    }
+
+    /// Rows of the binary part of the crt constraint of a reduction or a
+    /// multiplication
+    fn crt_rows() -> usize {
+        (0..(NUMBER_OF_LIMBS + 1) / 2)
+            .map(|i| {
+                let carry = usize::from(i > 0);
+                if 2 * i + 1 < NUMBER_OF_LIMBS {
+                    assert_zero_sum_rows(5 + carry)
+                } else {
+                    assert_zero_sum_rows(3 + carry)
+                }
+            })
+            .sum()
+    }
+
+    /// Maximum values of an integer assigned in remainder range
+    fn remainder(&self) -> MaxVals {
+        let msb_bit_len = self.rns.max_most_significant_reduced_limb.bits() as usize;
+        (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let bit_len = if i == NUMBER_OF_LIMBS - 1 {
+                    msb_bit_len
+                } else {
+                    BIT_LEN_LIMB
+                };
+                (big_uint::one() << bit_len) - 1usize
+            })
+            .collect()
+    }
+
+    /// Counts an integer assigned with range checked limbs where the most
+    /// significant limb has `msb_bit_len` bits
+    fn assign_integer(&mut self, msb_bit_len: u64) {
+        self.rows += (NUMBER_OF_LIMBS - 1) * Self::range_rows(BIT_LEN_LIMB)
+            + Self::range_rows(msb_bit_len as usize)
+            + compose_rows(NUMBER_OF_LIMBS);
+    }
+
+    fn reduce(&mut self) -> MaxVals {
+        let rns = Rc::clone(&self.rns);
+        self.assign_integer(rns.max_most_significant_reduced_limb.bits());
+        // quotient, residues, intermediate values, crt and native crt
+        self.rows += Self::range_rows(BIT_LEN_LIMB)
+            + (NUMBER_OF_LIMBS + 1) / 2 * Self::range_rows(rns.red_v_bit_len)
+            + NUMBER_OF_LIMBS * compose_rows(2)
+            + Self::crt_rows()
+            + 1;
+        self.remainder()
+    }
+
+    fn reduce_if_limb_values_exceeds_unreduced(&mut self, a: &MaxVals) -> MaxVals {
+        if a.iter().any(|limb| *limb > self.rns.max_unreduced_limb) {
+            self.reduce()
+        } else {
+            a.clone()
+        }
+    }
+
+    /// Reductions applied to an operand of a multiplication
+    fn reduce_operand(&mut self, a: &MaxVals) -> MaxVals {
+        let a = if a.iter().any(|limb| *limb > self.rns.max_reduced_limb) {
+            self.reduce()
+        } else {
+            a.clone()
+        };
+        if compose(a.clone(), BIT_LEN_LIMB) > self.rns.max_operand {
+            self.reduce()
+        } else {
+            a
+        }
+    }
+
+    /// Auxiliary value that `sub` adds so that limbs of the result don't
+    /// underflow
+    fn subtraction_aux(&self, b: &MaxVals) -> MaxVals {
+        let mut max_shift = 0usize;
+        for (max_val, aux) in b.iter().zip(self.rns.base_aux.iter()) {
+            let mut shift = 1;
+            let mut aux = aux.clone();
+            while *max_val > aux {
+                aux <<= 1usize;
+                max_shift = std::cmp::max(shift, max_shift);
+                shift += 1;
+            }
+        }
+        self.rns
+            .base_aux
+            .iter()
+            .map(|aux| aux << max_shift)
+            .collect()
+    }
+
+    /// Counts an operation that takes a row for each limb and the native value
+    fn limb_wise(&mut self) {
+        self.rows += NUMBER_OF_LIMBS + 1;
+    }
+
+    fn add(&mut self, a: &MaxVals, b: &MaxVals) -> MaxVals {
+        let a = self.reduce_if_limb_values_exceeds_unreduced(a);
+        let b = self.reduce_if_limb_values_exceeds_unreduced(b);
+        self.limb_wise();
+        a.iter().zip(b.iter()).map(|(a, b)| a + b).collect()
+    }
+
+    fn sub(&mut self, a: &MaxVals, b: &MaxVals) -> MaxVals {
+        let a = self.reduce_if_limb_values_exceeds_unreduced(a);
+        let b = self.reduce_if_limb_values_exceeds_unreduced(b);
+        let aux = self.subtraction_aux(&b);
+        self.limb_wise();
+        a.iter().zip(aux.iter()).map(|(a, aux)| a + aux).collect()
+    }
+
+    fn sub_sub(&mut self, a: &MaxVals, b_0: &MaxVals, b_1: &MaxVals) -> MaxVals {
+        let a = self.reduce_if_limb_values_exceeds_unreduced(a);
+        let b_0 = self.reduce_if_limb_values_exceeds_unreduced(b_0);
+        let b_1 = self.reduce_if_limb_values_exceeds_unreduced(b_1);
+        let b: MaxVals = b_0
+            .iter()
+            .zip(b_1.iter())
+            .map(|(b_0, b_1)| b_0 + b_1)
+            .collect();
+        let aux = self.subtraction_aux(&b);
+        self.limb_wise();
+        a.iter().zip(aux.iter()).map(|(a, aux)| a + aux).collect()
+    }
+
+    fn mul2(&mut self, a: &MaxVals) -> MaxVals {
+        self.limb_wise();
+        a.iter().map(|a| a * 2usize).collect()
+    }
+
+    fn mul3(&mut self, a: &MaxVals) -> MaxVals {
+        self.limb_wise();
+        a.iter().map(|a| a * 3usize).collect()
+    }
+
+    fn add_constant(&mut self, a: &MaxVals, constant: &MaxVals) -> MaxVals {
+        let a = self.reduce_if_limb_values_exceeds_unreduced(a);
+        self.limb_wise();
+        a.iter().zip(constant.iter()).map(|(a, c)| a + c).collect()
+    }
+
+    fn select(&mut self, a: &MaxVals, b: &MaxVals) -> MaxVals {
+        self.limb_wise();
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| std::cmp::max(a, b).clone())
+            .collect()
+    }
+
+    /// Counts the rows of a multiplication over already reduced operands.
+    /// Square and incomplete division lay out the same rows.
+    fn mul_generic(&mut self) -> MaxVals {
+        let rns = Rc::clone(&self.rns);
+        // result and quotient
+        self.assign_integer(rns.max_most_significant_reduced_limb.bits());
+        self.assign_integer(rns.max_most_significant_mul_quotient_limb.bits());
+        // residues, intermediate values, crt and native crt
+        self.rows += (NUMBER_OF_LIMBS + 1) / 2 * Self::range_rows(rns.mul_v_bit_len)
+            + NUMBER_OF_LIMBS * (NUMBER_OF_LIMBS + 1) / 2
+            + Self::crt_rows()
+            + 1;
+        self.remainder()
+    }
+
+    fn mul(&mut self, a: &MaxVals, b: &MaxVals) -> MaxVals {
+        self.reduce_operand(a);
+        self.reduce_operand(b);
+        self.mul_generic()
+    }
+
+    fn square(&mut self, a: &MaxVals) -> MaxVals {
+        self.reduce_operand(a);
+        self.mul_generic()
+    }
+
+    fn div_incomplete(&mut self, a: &MaxVals, b: &MaxVals) -> MaxVals {
+        self.mul(a, b)
+    }
+
+    fn assert_not_equal(&mut self, a: &MaxVals, b: &MaxVals) {
+        self.sub(a, b);
+        self.reduce();
+        // four zero checks of three rows each, two `nand` and the differences
+        // of the result with the wrong modulus
+        self.rows += 4 * 3 + 2 + 2;
+    }
+
+    /// Counts `decompose` of an integer in the wrong field
+    fn decompose(&mut self, a: &MaxVals) {
+        // `assert_in_field`
+        self.reduce_operand(a);
+        self.assign_integer(self.rns.max_most_significant_reduced_limb.bits());
+        self.rows += (NUMBER_OF_LIMBS - 1) + NUMBER_OF_LIMBS;
+
+        let wrong_modulus_bit_len = self.rns.wrong_modulus.bits() as usize;
+        for idx in 0..NUMBER_OF_LIMBS {
+            let number_of_bits =
+                BIT_LEN_LIMB.min(wrong_modulus_bit_len.saturating_sub(idx * BIT_LEN_LIMB));
+            self.rows += match number_of_bits {
+                // limb above the wrong modulus is asserted to be zero
+                0 => 1,
+                // bits, composition and equality with the limb
+                _ => number_of_bits + compose_rows(number_of_bits) + 1,
+            };
+        }
+    }
+}
+
+/// Counts rows that the general ecc chip lays out for multiplication
+struct EccRows<
+    Emulated: CurveAffine,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    base: IntegerRows<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    scalar: IntegerRows<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    // limbs of the curve constant `a` if it is not zero
+    parameter_a: Option<MaxVals>,
+}
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > EccRows<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn new() -> Self {
+        let (rns_base, rns_scalar) =
+            GeneralEccChip::<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+        let base = IntegerRows::new(rns_base);
+        let has_parameter_a =
+            GeneralEccChip::<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::has_parameter_a();
+        let parameter_a = has_parameter_a.then(|| {
+            Integer::from_fe(Emulated::a(), Rc::clone(&base.rns))
+                .limbs()
+                .into_iter()
+                .map(fe_to_big)
+                .collect()
+        });
+        Self {
+            base,
+            scalar: IntegerRows::new(rns_scalar),
+            parameter_a,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.base.rows + self.scalar.rows
+    }
+
+    /// Point assigned with `assign_point`
+    fn assigned_point(&self) -> PointMax {
+        PointMax {
+            x: self.base.remainder(),
+            y: self.base.remainder(),
+        }
+    }
+
+    /// Decomposition of scalars assigned in remainder range and the padding
+    /// that they share
+    fn decompose_scalars(&mut self, number_of_scalars: usize, window_size: usize) {
+        let scalar = self.scalar.remainder();
+        for _ in 0..number_of_scalars {
+            self.scalar.decompose(&scalar);
+        }
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        if number_of_bits % window_size != 0 {
+            self.scalar.rows += 1;
+        }
+    }
+
+    fn select(&mut self, p_0: &PointMax, p_1: &PointMax) -> PointMax {
+        PointMax {
+            x: self.base.select(&p_0.x, &p_1.x),
+            y: self.base.select(&p_0.y, &p_1.y),
+        }
+    }
+
+    fn select_multi(&mut self, table: &[PointMax], window_size: usize) -> PointMax {
+        let mut reducer = table[..1 << window_size].to_vec();
+        while reducer.len() > 1 {
+            reducer = reducer
+                .chunks(2)
+                .map(|pair| self.select(&pair[1], &pair[0]))
+                .collect();
+        }
+        reducer.pop().unwrap()
+    }
+
+    fn add(&mut self, a: &PointMax, b: &PointMax) -> PointMax {
+        let ch = &mut self.base;
+        ch.assert_not_equal(&a.x, &b.x);
+        let numerator = &ch.sub(&b.y, &a.y);
+        let denominator = &ch.sub(&b.x, &a.x);
+        let lambda = &ch.div_incomplete(numerator, denominator);
+        let lambda_square = &ch.square(lambda);
+        let x = ch.sub_sub(lambda_square, &a.x, &b.x);
+        let t = &ch.sub(&a.x, &x);
+        let t = &ch.mul(t, lambda);
+        let y = ch.sub(t, &a.y);
+        PointMax { x, y }
+    }
+
+    fn double(&mut self, point: &PointMax) -> PointMax {
+        let ch = &mut self.base;
+        let x_0_square = &ch.square(&point.x);
+        let numerator = ch.mul3(x_0_square);
+        let numerator = &match &self.parameter_a {
+            Some(a) => ch.add_constant(&numerator, a),
+            None => numerator,
+        };
+        let denominator = &ch.mul2(&point.y);
+        let lambda = &ch.div_incomplete(numerator, denominator);
+        let lambda_square = &ch.square(lambda);
+        let x = ch.sub_sub(lambda_square, &point.x, &point.x);
+        let t = &ch.sub(&point.x, &x);
+        let t = &ch.mul(lambda, t);
+        let y = ch.sub(t, &point.y);
+        PointMax { x, y }
+    }
+
+    fn double_n(&mut self, point: &PointMax, logn: usize) -> PointMax {
+        let mut acc = point.clone();
+        for _ in 0..logn {
+            acc = self.double(&acc);
+        }
+        acc
+    }
+
+    fn ladder(&mut self, to_double: &PointMax, to_add: &PointMax) -> PointMax {
+        let ch = &mut self.base;
+        let numerator = &ch.sub(&to_add.y, &to_double.y);
+        let denominator = &ch.sub(&to_add.x, &to_double.x);
+        let lambda_0 = &ch.div_incomplete(numerator, denominator);
+        let lambda_0_square = &ch.square(lambda_0);
+        let x_3 = &ch.sub_sub(lambda_0_square, &to_add.x, &to_double.x);
+        let numerator = &ch.mul2(&to_double.y);
+        let denominator = &ch.sub(x_3, &to_double.x);
+        let lambda_1 = &ch.div_incomplete(numerator, denominator);
+        let lambda_1 = &ch.add(lambda_0, lambda_1);
+        let lambda_1_square = &ch.square(lambda_1);
+        let x_4 = ch.sub_sub(lambda_1_square, x_3, &to_double.x);
+        let t = &ch.sub(&x_4, &to_double.x);
+        let t = &ch.mul(t, lambda_1);
+        let y_4 = ch.sub(t, &to_double.y);
+        PointMax { x: x_4, y: y_4 }
+    }
+
+    fn incremental_table(
+        &mut self,
+        aux: &PointMax,
+        point: &PointMax,
+        window_size: usize,
+    ) -> Vec<PointMax> {
+        let mut table = vec![aux.clone()];
+        for i in 0..(1 << window_size) - 1 {
+            let next = self.add(&table[i], point);
+            table.push(next);
+        }
+        table
+    }
+
+    fn number_of_windows(&self, window_size: usize) -> usize {
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        (number_of_bits + window_size - 1) / window_size
+    }
+}
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns number of rows that `mul` takes with the given window size.
+    /// Inputs and auxiliary points are not included.
+    ///
+    /// Rows are counted from the row costs of each step of `mul` without
+    /// synthesizing it: scalar decomposition, table additions, selections,
+    /// doublings, ladders and the final addition of the auxiliary point.
+    /// Point, scalar and auxiliary points are expected to be assigned in
+    /// remainder range, as `assign_point`, `assign_aux` and
+    /// `assign_integer` with `Range::Remainder` do, in a region that caches
+    /// neither constants nor decompositions.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` is zero or exceeds
+    /// `MAX_WINDOW_SIZE`
+    pub fn mul_row_estimate(window_size: usize) -> Result<usize, Error> {
+        check_window_size(window_size)?;
+        let mut model = EccRows::<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new();
+        let point = model.assigned_point();
+
+        let table = model.incremental_table(&point, &point, window_size);
+        model.decompose_scalars(1, window_size);
+
+        let mut acc = model.select_multi(&table, window_size);
+        acc = model.double_n(&acc, window_size);
+        let to_add = model.select_multi(&table, window_size);
+        acc = model.add(&acc, &to_add);
+        for _ in 2..model.number_of_windows(window_size) {
+            acc = model.double_n(&acc, window_size - 1);
+            let to_add = model.select_multi(&table, window_size);
+            acc = model.ladder(&acc, &to_add);
+        }
+        model.add(&acc, &point);
+
+        Ok(model.rows())
+    }
+
+    /// Returns number of rows that `mul_batch_1d_horizontal` takes with the
+    /// given number of pairs and window size. Pairs are assumed to have
+    /// distinct points. Inputs and auxiliary points are not included.
+    /// Inputs are expected as in `mul_row_estimate`.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` is zero or exceeds
+    /// `MAX_WINDOW_SIZE` or if there are no pairs
+    pub fn mul_batch_row_estimate(
+        number_of_pairs: usize,
+        window_size: usize,
+    ) -> Result<usize, Error> {
+        check_window_size(window_size)?;
+        if number_of_pairs == 0 {
+            return Err(Error::Synthesis);
+        }
+        let mut model = EccRows::<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new();
+        let point = model.assigned_point();
+
+        model.decompose_scalars(number_of_pairs, window_size);
+
+        let mut binary_aux = vec![point.clone()];
+        for i in 1..number_of_pairs {
+            let doubled = model.double(&binary_aux[i - 1]);
+            binary_aux.push(doubled);
+        }
+        let tables: Vec<Vec<PointMax>> = binary_aux
+            .iter()
+            .map(|aux| model.incremental_table(aux, &point, window_size))
+            .collect();
+
+        let mut acc = model.select_multi(&tables[0], window_size);
+        for table in tables.iter().skip(1) {
+            let to_add = model.select_multi(table, window_size);
+            acc = model.add(&acc, &to_add);
+        }
+        for _ in 1..model.number_of_windows(window_size) {
+            acc = model.double_n(&acc, window_size);
+            for table in tables.iter() {
+                let to_add = model.select_multi(table, window_size);
+                acc = model.add(&acc, &to_add);
+            }
+        }
+        model.add(&acc, &point);
+
+        Ok(model.rows())
+    }
+
+    /// Returns the window size up to `MAX_WINDOW_SIZE` that minimizes rows of
+    /// `mul` for a single scalar or rows of `mul_batch_1d_horizontal` for
+    /// `number_of_scalars` pairs. Rows are found with `mul_row_estimate` and
+    /// `mul_batch_row_estimate`. Smaller window is preferred on ties.
+    ///
+    /// Returns `Error::Synthesis` if there are no scalars.
+    pub fn optimal_window_size(number_of_scalars: usize) -> Result<usize, Error> {
+        if number_of_scalars == 0 {
+            return Err(Error::Synthesis);
+        }
+        let rows = |window_size| match number_of_scalars {
+            1 => Self::mul_row_estimate(window_size),
+            _ => Self::mul_batch_row_estimate(number_of_scalars, window_size),
+        };
+        let mut optimal = (1, rows(1)?);
+        for window_size in 2..=MAX_WINDOW_SIZE {
+            let rows = rows(window_size)?;
+            if rows < optimal.1 {
+                optimal = (window_size, rows);
+            }
+        }
+        Ok(optimal.0)
+    }
+}
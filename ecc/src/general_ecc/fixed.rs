@@ -0,0 +1,270 @@
+use super::{AssignedPoint, GeneralEccChip};
+use crate::integer::{AssignedInteger, IntegerInstructions};
+use crate::{aux_generator_from_tag, check_window_size, halo2, Table};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::PrimeField;
+use halo2::halo2curves::group::{prime::PrimeCurveAffine, Curve, Group};
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Offset point that is added to each fixed base table so that
+    /// incomplete addition formula can be used. It is hashed to the curve
+    /// from a fixed tag so that no one knows its relation with the base point
+    /// and keygen and prover always agree on the constants.
+    fn fixed_base_offset() -> Emulated::Curve {
+        Self::fixed_base_offset_at(0)
+    }
+
+    /// Offset point of the fixed base at `index` of a multi-product so that
+    /// independent offsets are added for different bases
+    pub(super) fn fixed_base_offset_at(index: u64) -> Emulated::Curve {
+        let tag = format!("fixed-base-offset-{index}");
+        aux_generator_from_tag::<Emulated>(tag.as_bytes()).to_curve()
+    }
+
+    /// Precomputes tables for fixed base multiplication out of circuit.
+    ///
+    /// Table of the `i`th window contains `2^i * A + j * 2^(i * window_size) *
+    /// P` for `j` in `0..2^window_size` where `A` is the offset point. Also
+    /// returns the point that cancels the sum of offsets.
+    pub(super) fn make_fixed_base_tables(
+        point: Emulated,
+        number_of_windows: usize,
+        window_size: usize,
+    ) -> Result<(Vec<Vec<Emulated>>, Emulated), Error> {
+        Self::make_fixed_base_tables_with_offset(
+            point,
+            Self::fixed_base_offset(),
+            number_of_windows,
+            window_size,
+        )
+    }
+
+    /// Precomputes tables as `make_fixed_base_tables` with the given offset
+    /// point. Returns `Error::Synthesis` if `window_size` is not supported.
+    fn make_fixed_base_tables_with_offset(
+        point: Emulated,
+        offset: Emulated::Curve,
+        number_of_windows: usize,
+        window_size: usize,
+    ) -> Result<(Vec<Vec<Emulated>>, Emulated), Error> {
+        check_window_size(window_size)?;
+        let mut base = point.to_curve();
+        let mut window_offset = offset;
+        let tables: Vec<Vec<Emulated>> = (0..number_of_windows)
+            .map(|_| {
+                let mut entry = window_offset;
+                let table: Vec<Emulated> = (0..1 << window_size)
+                    .map(|_| {
+                        let current = entry.to_affine();
+                        entry += base;
+                        current
+                    })
+                    .collect();
+                base = (0..window_size).fold(base, |base, _| base.double());
+                window_offset = window_offset.double();
+                table
+            })
+            .collect();
+        // sum of offsets is `(2^number_of_windows - 1) * A`
+        let to_sub = (offset - window_offset).to_affine();
+        Ok((tables, to_sub))
+    }
+
+    /// Scalar multiplication of a point that is known at synthesis time.
+    ///
+    /// Each window gets its own table of precomputed multiples which are
+    /// assigned as constants, so that no in circuit table construction nor
+    /// doubling is required. Result is the sum of selected entries of each
+    /// window.
+    pub fn mul_fixed_base(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: Emulated,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+
+        let scalar_chip = self.scalar_field_chip();
+        let decomposed = &mut scalar_chip.decompose(region, scalar)?;
+        self.pad(region, decomposed, window_size)?;
+        let mut windowed = Self::window(decomposed.to_vec(), window_size);
+        // least significant window first
+        windowed.0.reverse();
+
+        let (tables, to_sub) = Self::make_fixed_base_tables(point, windowed.0.len(), window_size)?;
+
+        let mut acc: Option<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = None;
+        for (selector, table) in windowed.0.iter().zip(tables.into_iter()) {
+            let table = Table(
+                table
+                    .into_iter()
+                    .map(|entry| self.assign_constant(region, entry))
+                    .collect::<Result<_, Error>>()?,
+            );
+            let selected = self.select_multi(region, selector, &table)?;
+            acc = Some(match acc {
+                None => selected,
+                Some(acc) => self.add(region, &acc, &selected)?,
+            });
+        }
+
+        let to_sub = self.assign_constant(region, to_sub)?;
+        self.add(region, &acc.unwrap(), &to_sub)
+    }
+
+    /// Scalar multiplication of a point that is known at synthesis time with
+    /// many scalars. Returns `[s_i]P` for each scalar in the given order.
+    ///
+    /// Constant tables are the same ones of `mul_fixed_base` but they are
+    /// assigned only once and shared across all scalars together with the
+    /// offset correction point.
+    ///
+    /// Returns `Error::Synthesis` if no scalar is given.
+    pub fn mul_fixed_base_batch(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: Emulated,
+        scalars: &[AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        window_size: usize,
+    ) -> Result<Vec<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error> {
+        check_window_size(window_size)?;
+        if scalars.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        let scalar_chip = self.scalar_field_chip();
+        let windowed = scalars
+            .iter()
+            .map(|scalar| {
+                let decomposed = &mut scalar_chip.decompose(region, scalar)?;
+                self.pad(region, decomposed, window_size)?;
+                let mut windowed = Self::window(decomposed.to_vec(), window_size);
+                // least significant window first
+                windowed.0.reverse();
+                Ok(windowed)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let number_of_windows = windowed[0].0.len();
+
+        let (tables, to_sub) = Self::make_fixed_base_tables(point, number_of_windows, window_size)?;
+        let tables = tables
+            .into_iter()
+            .map(|table| {
+                Ok(Table(
+                    table
+                        .into_iter()
+                        .map(|entry| self.assign_constant(region, entry))
+                        .collect::<Result<_, Error>>()?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let to_sub = self.assign_constant(region, to_sub)?;
+
+        windowed
+            .iter()
+            .map(|windowed| {
+                let mut acc: Option<
+                    AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                > = None;
+                for (selector, table) in windowed.0.iter().zip(tables.iter()) {
+                    let selected = self.select_multi(region, selector, table)?;
+                    acc = Some(match acc {
+                        None => selected,
+                        Some(acc) => self.add(region, &acc, &selected)?,
+                    });
+                }
+                self.add(region, &acc.unwrap(), &to_sub)
+            })
+            .collect()
+    }
+
+    /// Computes multi-product of fixed and variable bases
+    ///
+    /// Given points known at synthesis time with their scalars `[(G_0, e_0),
+    /// ...]` and assigned points with their scalars `[(P_0, s_0), ...]`
+    /// returns `G_0 * e_0 + ... + P_0 * s_0 + ...`.
+    ///
+    /// Each fixed base gets constant tables as in `mul_fixed_base` with its
+    /// own offset point and selected entries of all fixed bases are summed
+    /// into a single accumulator. Variable bases are multiplied in a single
+    /// ladder with `mul_batch_1d_horizontal`, so auxiliary point must be
+    /// assigned for the number of distinct variable points or for the number
+    /// of variable pairs. Offsets of fixed bases are cancelled at once with a
+    /// constant point.
+    ///
+    /// Returns `Error::Synthesis` if both lists are empty.
+    #[allow(clippy::type_complexity)]
+    pub fn msm_mixed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        fixed: Vec<(
+            Emulated,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        variable: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        if fixed.is_empty() && variable.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        let variable = if variable.is_empty() {
+            None
+        } else {
+            Some(self.mul_batch_1d_horizontal(region, variable, window_size)?)
+        };
+        if fixed.is_empty() {
+            return Ok(variable.unwrap());
+        }
+
+        let scalar_chip = self.scalar_field_chip();
+        let mut acc = variable;
+        let mut to_sub = Emulated::Curve::identity();
+        for (index, (point, scalar)) in fixed.into_iter().enumerate() {
+            let decomposed = &mut scalar_chip.decompose(region, &scalar)?;
+            self.pad(region, decomposed, window_size)?;
+            let mut windowed = Self::window(decomposed.to_vec(), window_size);
+            // least significant window first
+            windowed.0.reverse();
+
+            let offset = Self::fixed_base_offset_at(index as u64);
+            let (tables, correction) = Self::make_fixed_base_tables_with_offset(
+                point,
+                offset,
+                windowed.0.len(),
+                window_size,
+            )?;
+            to_sub += correction.to_curve();
+
+            for (selector, table) in windowed.0.iter().zip(tables.into_iter()) {
+                let table = Table(
+                    table
+                        .into_iter()
+                        .map(|entry| self.assign_constant(region, entry))
+                        .collect::<Result<_, Error>>()?,
+                );
+                let selected = self.select_multi(region, selector, &table)?;
+                acc = Some(match acc {
+                    None => selected,
+                    Some(acc) => self.add(region, &acc, &selected)?,
+                });
+            }
+        }
+
+        let to_sub = self.assign_constant(region, to_sub.to_affine())?;
+        self.add(region, &acc.unwrap(), &to_sub)
+    }
+}
@@ -0,0 +1,193 @@
+use super::{AssignedPoint, GeneralEccChip};
+use crate::integer::rns::{Common, Integer};
+use crate::integer::{AssignedInteger, IntegerInstructions, Range};
+use crate::maingate::{big_to_fe, fe_to_big, modulus, MainGateInstructions};
+use crate::{check_window_size, halo2, Windowed};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::PrimeField;
+use halo2::halo2curves::secp256k1::Secp256k1Affine;
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+use num_bigint::BigUint as big_uint;
+use num_traits::Num;
+
+/// Curves that have an efficiently computable endomorphism
+/// $\phi(x, y) = (\beta x, y)$ with $\phi(P) = \lambda P$
+pub trait HasEndomorphism: CurveAffine {
+    /// Upper bound of the bit length of the absolute values of $k_1$ and
+    /// $k_2$ that `decompose_scalar` returns
+    const MAX_DECOMPOSED_BITS: usize;
+
+    /// Returns $\beta$, a non trivial cube root of unity in the base field
+    fn beta() -> Self::Base;
+
+    /// Returns $\lambda$, a non trivial cube root of unity in the scalar field
+    /// that corresponds to $\beta$
+    fn lambda() -> Self::ScalarExt;
+
+    /// Splits the scalar into $(k_1, k_2)$ such that $k = k_1 + k_2 \lambda$
+    /// where $k_1$ and $k_2$ or their negations are about half width
+    fn decompose_scalar(k: &Self::ScalarExt) -> (Self::ScalarExt, Self::ScalarExt);
+}
+
+fn from_hex(e: &str) -> big_uint {
+    big_uint::from_str_radix(e, 16).unwrap()
+}
+
+impl HasEndomorphism for Secp256k1Affine {
+    const MAX_DECOMPOSED_BITS: usize = 129;
+
+    fn beta() -> Self::Base {
+        big_to_fe(from_hex(
+            "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+        ))
+    }
+
+    fn lambda() -> Self::ScalarExt {
+        big_to_fe(from_hex(
+            "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+        ))
+    }
+
+    fn decompose_scalar(k: &Self::ScalarExt) -> (Self::ScalarExt, Self::ScalarExt) {
+        // short basis of the lattice {(a, b) : a + b * lambda = 0}
+        // where b_1 is negative and b_2 = a_1
+        let a_1 = from_hex("3086d221a7d46bcde86c90e49284eb15");
+        let minus_b_1 = from_hex("e4437ed6010e88286f547fa90abfe4c3");
+        let a_2 = from_hex("114ca50f7a8e2f3f657c1108d9d44cfd8");
+        let b_2 = a_1.clone();
+
+        let n = modulus::<Self::ScalarExt>();
+        let k_big = fe_to_big(*k);
+        let round_div = |e: big_uint| (e + (&n >> 1usize)) / &n;
+        let c_1 = round_div(&b_2 * &k_big);
+        let c_2 = round_div(&minus_b_1 * &k_big);
+
+        let fe = |e: &big_uint| big_to_fe::<Self::ScalarExt>(e % &n);
+        let k_1 = *k - fe(&(&c_1 * &a_1)) - fe(&(&c_2 * &a_2));
+        let k_2 = fe(&(&c_1 * &minus_b_1)) - fe(&(&c_2 * &b_2));
+        (k_1, k_2)
+    }
+}
+
+/// Returns the absolute value of a scalar and whether it is negative, that
+/// is greater than the half of the modulus
+fn split_sign<F: PrimeField>(e: F) -> (F, bool) {
+    if fe_to_big(e) > modulus::<F>() >> 1usize {
+        (-e, true)
+    } else {
+        (e, false)
+    }
+}
+
+impl<
+        Emulated: HasEndomorphism,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Applies the endomorphism to the point, $(x, y) \mapsto (\beta x, y)$
+    pub fn endomorphism(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let beta = Integer::from_fe(Emulated::beta(), self.rns_base());
+        let x = self
+            .base_field_chip()
+            .mul_constant(region, point.x(), &beta)?;
+        Ok(AssignedPoint::new(x, point.y().clone()))
+    }
+
+    /// Scalar multiplication using the endomorphism of the curve. Scalar is
+    /// split into $k_1$ and $k_2$ where $k = k_1 + k_2 \lambda$. Each part is
+    /// witnessed as its absolute value and a sign bit, the absolute values
+    /// are constrained to fit in `MAX_DECOMPOSED_BITS` bits and the result
+    /// is computed as $|k_1| (\pm P) + |k_2| (\pm \phi(P))$ in a batch so
+    /// that the ladder runs over about half of the windows of `mul`.
+    ///
+    /// Auxiliary point must be assigned with `assign_aux_bounded` for the
+    /// `window_size` and `MAX_DECOMPOSED_BITS`. Returns [`RowLimitExceeded`]
+    /// if the batch goes past the limit set with `set_max_rows`.
+    ///
+    /// [`RowLimitExceeded`]: crate::RowLimitExceeded
+    pub fn mul_glv(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let max_bits = Emulated::MAX_DECOMPOSED_BITS;
+        // at least two windows are expected by the ladder
+        if max_bits <= window_size {
+            return Err(Error::Synthesis);
+        }
+        let aux = self.get_mul_aux_bounded(window_size, max_bits)?;
+
+        let main_gate = self.main_gate();
+        let scalar_chip = self.scalar_field_chip();
+
+        let decomposed = scalar.integer().map(|k| {
+            let k = big_to_fe::<Emulated::ScalarExt>(k.value() % modulus::<Emulated::ScalarExt>());
+            let (k_1, k_2) = Emulated::decompose_scalar(&k);
+            [split_sign(k_1), split_sign(k_2)]
+        });
+
+        let endo = self.endomorphism(region, point)?;
+        let mut signed = vec![];
+        let mut points = vec![];
+        let mut windowed_scalars: Vec<Windowed<N>> = vec![];
+        for (i, base) in [point.clone(), endo].iter().enumerate() {
+            let part = decomposed.map(|parts| parts[i]);
+            let abs = self.new_unassigned_scalar(part.map(|(abs, _)| abs));
+            let abs = scalar_chip.assign_integer(region, abs, Range::Remainder)?;
+            let sign = main_gate.assign_bit(
+                region,
+                part.map(|(_, negative)| if negative { N::ONE } else { N::ZERO }),
+            )?;
+
+            // higher bits of the absolute value must be zero
+            let mut bits = scalar_chip.decompose(region, &abs)?;
+            for bit in bits.drain(max_bits..) {
+                main_gate.assert_zero(region, &bit)?;
+            }
+            windowed_scalars.push(self.window_bits(region, bits, window_size)?);
+
+            signed.push(scalar_chip.neg_if(region, &abs, &sign)?);
+            points.push(self.neg_if(region, base, &sign)?);
+        }
+
+        // k = k_1 + k_2 * lambda
+        let lambda = Integer::from_fe(Emulated::lambda(), self.rns_scalar());
+        let k_2_lambda = scalar_chip.mul_constant(region, &signed[1], &lambda)?;
+        let k = scalar_chip.add(region, &signed[0], &k_2_lambda)?;
+        scalar_chip.assert_equal(region, &k, scalar)?;
+
+        let binary_aux = self.binary_aux(region, &aux.to_add, 2)?;
+        let tables = points
+            .iter()
+            .zip(binary_aux.iter())
+            .map(|(point, binary_aux)| {
+                let table = self.make_incremental_table(region, binary_aux, point, window_size)?;
+                self.check_max_rows(region, "mul_glv")?;
+                Ok(table)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        // second table is built on the doubled auxiliary generator so the
+        // contribution to subtract is three times the one of a single scalar
+        let to_sub = self.double(region, &aux.to_sub)?;
+        let to_sub = self.add(region, &to_sub, &aux.to_sub)?;
+
+        self.mul_batch_windowed(
+            region,
+            &tables,
+            &windowed_scalars,
+            &to_sub,
+            window_size,
+            "mul_glv",
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use super::{AssignedPoint, GeneralEccChip};
+use crate::integer::{AssignedInteger, IntegerChip};
+use crate::maingate::{MainGateInstructions, Term};
+use crate::{check_window_size, halo2, Selector, Table};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::PrimeField;
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Number of keys that a table of the given window size occupies in the
+    /// dynamic lookup table. Returns `Error::Synthesis` if `window_size` is
+    /// not supported.
+    pub fn lookup_table_width(window_size: usize) -> Result<usize, Error> {
+        check_window_size(window_size)?;
+        let width =
+            IntegerChip::<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::dynamic_table_width();
+        Ok(2 * (1 << window_size) * width)
+    }
+
+    /// Returns coordinates of table entries
+    #[allow(clippy::type_complexity)]
+    fn table_coordinates(
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> (
+        Vec<AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        Vec<AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    ) {
+        table
+            .0
+            .iter()
+            .map(|point| (point.x().clone(), point.y().clone()))
+            .unzip()
+    }
+
+    /// Loads the table to the dynamic lookup table. `x` coordinates are placed
+    /// starting from the `tag` and then `y` coordinates follow. Table
+    /// occupies `lookup_table_width` keys and key ranges of different tables
+    /// must not overlap.
+    pub fn load_lookup_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        tag: N,
+    ) -> Result<(), Error> {
+        let ch = self.base_field_chip();
+        let (xs, ys) = Self::table_coordinates(table);
+        ch.load_dynamic_table(region, tag, &xs)?;
+        ch.load_dynamic_table(region, Self::y_tag(table, tag), &ys)
+    }
+
+    /// Start of the keys of `y` coordinates
+    fn y_tag(table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, tag: N) -> N {
+        let width =
+            IntegerChip::<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::dynamic_table_width();
+        tag + N::from((table.0.len() * width) as u64)
+    }
+
+    /// Selects a point in the table using a selector as `select_multi` does.
+    /// Instead of the binary reduction the index is composed from the
+    /// selector bits and the point is looked up in the table loaded with
+    /// `load_lookup_table` under the same `tag`.
+    pub(crate) fn select_multi_lookup(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        selector: &Selector<N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        tag: N,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let number_of_points = table.0.len();
+        let number_of_selectors = selector.0.len();
+        check_window_size(number_of_selectors)?;
+        assert!(number_of_points >= 1 << number_of_selectors);
+
+        // first selector is the least significant bit of the index
+        let main_gate = self.main_gate();
+        let mut base = N::ONE;
+        let terms: Vec<Term<N>> = selector
+            .0
+            .iter()
+            .map(|bit| {
+                let term = Term::Assigned(bit, base);
+                base = base.double();
+                term
+            })
+            .collect();
+        let index = &main_gate.compose(region, &terms, N::ZERO)?;
+
+        let ch = self.base_field_chip();
+        let (xs, ys) = Self::table_coordinates(table);
+        let x = ch.dynamic_lookup(region, tag, index, &xs)?;
+        let y = ch.dynamic_lookup(region, Self::y_tag(table, tag), index, &ys)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Scalar multiplication of a point in the EC as `mul` does where the
+    /// points of the incremental table are selected with the dynamic lookup.
+    /// Cheaper than `mul` for large window sizes. `tag` is the start of the
+    /// keys that the table occupies, see `load_lookup_table`.
+    pub fn mul_lookup(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+        tag: N,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let aux = self.get_mul_aux(window_size, 1)?;
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+        self.load_lookup_table(region, table, tag)?;
+
+        let acc =
+            self.accumulate_windowed_with(region, &windowed, window_size, |region, selector| {
+                self.select_multi_lookup(region, selector, table, tag)
+            })?;
+        self.add(region, &acc, &aux.to_sub)
+    }
+}
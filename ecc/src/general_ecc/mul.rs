@@ -1,9 +1,13 @@
 use super::{AssignedPoint, GeneralEccChip};
 use crate::integer::{AssignedInteger, IntegerInstructions};
-use crate::maingate::{AssignedCondition, MainGateInstructions};
-use crate::{halo2, Selector, Table, Windowed};
+use crate::maingate::{fe_to_big, AssignedCondition, AssignedValue, MainGateInstructions};
+use crate::{
+    check_window_size, halo2, make_mul_aux, windows_from_bits, AssignedPointOrIdentity, MulAux,
+    MulProfile, MulStrategy, Selector, SignedSelector, Table, Windowed, MAX_WINDOW_SIZE,
+};
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::PrimeField;
+use halo2::halo2curves::group::{prime::PrimeCurveAffine, Curve, Group};
 use halo2::plonk::Error;
 use integer::maingate::RegionCtx;
 
@@ -14,54 +18,85 @@ impl<
         const BIT_LEN_LIMB: usize,
     > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
-    /// Pads scalar up to the next window_size mul
-    fn pad(
+    /// Pads little endian decomposition of a scalar with zeros up to the next
+    /// window_size mul and reverses it into big endian order that
+    /// `windows_from_bits` expects
+    pub fn pad(
         &self,
         region: &mut RegionCtx<'_, N>,
         bits: &mut Vec<AssignedCondition<N>>,
         window_size: usize,
     ) -> Result<(), Error> {
+        let zero = self.padding_zero(region, window_size)?;
+        Self::pad_with(bits, zero.as_ref(), window_size);
+        Ok(())
+    }
+
+    /// Assigns the zero constant that padding bits are copied from. Returns
+    /// `None` if the bit length of the scalar field is a multiple of
+    /// `window_size` and no padding is required.
+    pub(super) fn padding_zero(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        window_size: usize,
+    ) -> Result<Option<AssignedCondition<N>>, Error> {
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        if number_of_bits % window_size == 0 {
+            return Ok(None);
+        }
+        self.main_gate().assign_constant(region, N::ZERO).map(Some)
+    }
+
+    /// Pads scalar up to the next window_size mul with the zero that
+    /// `padding_zero` returns. The same zero can be shared across all scalars
+    /// of a batch.
+    pub(super) fn pad_with(
+        bits: &mut Vec<AssignedCondition<N>>,
+        zero: Option<&AssignedCondition<N>>,
+        window_size: usize,
+    ) {
         assert_eq!(bits.len(), Emulated::ScalarExt::NUM_BITS as usize);
 
-        // TODO: This is a tmp workaround. Instead of padding with zeros we can use a
-        // shorter ending window.
+        // Padding path is kept for `mul` and batch multiplication. See
+        // `mul_unpadded` for the variant with a shorter ending window.
         let padding_offset = (window_size - (bits.len() % window_size)) % window_size;
-        let zeros: Vec<AssignedCondition<N>> = (0..padding_offset)
-            .map(|_| self.main_gate().assign_constant(region, N::ZERO))
-            .collect::<Result<_, Error>>()?;
-        bits.extend(zeros);
+        if padding_offset > 0 {
+            let zero = zero.expect("padding requires a zero");
+            bits.extend(std::iter::repeat(zero.clone()).take(padding_offset));
+        }
         bits.reverse();
+    }
 
-        Ok(())
+    /// Splits the bit representation of a scalar into windows. See
+    /// `windows_from_bits`.
+    pub(super) fn window(bits: Vec<AssignedCondition<N>>, window_size: usize) -> Windowed<N> {
+        windows_from_bits(bits, window_size)
     }
 
-    /// Splits the bit representation of a scalar into windows
-    fn window(bits: Vec<AssignedCondition<N>>, window_size: usize) -> Windowed<N> {
-        assert_eq!(bits.len() % window_size, 0);
-        let number_of_windows = bits.len() / window_size;
-        Windowed(
-            (0..number_of_windows)
-                .map(|i| {
-                    let mut selector: Vec<AssignedCondition<N>> = (0..window_size)
-                        .map(|j| bits[i * window_size + j].clone())
-                        .collect();
-                    selector.reverse();
-                    Selector(selector)
-                })
-                .collect(),
-        )
+    /// Splits the bit representation of a scalar into windows without padding.
+    /// Bits are expected in little endian order. Windows are returned most
+    /// significant first and the most significant window is shorter if the
+    /// number of bits is not a multiple of the window size
+    fn window_unpadded(bits: &[AssignedCondition<N>], window_size: usize) -> Windowed<N> {
+        let mut windowed: Vec<Selector<N>> = bits
+            .chunks(window_size)
+            .map(|chunk| Selector(chunk.to_vec()))
+            .collect();
+        windowed.reverse();
+        Windowed(windowed)
     }
 
     /// Constructs table for efficient multiplication algorithm
     /// The table contains precomputed point values that allow to trade
     /// additions for selections
-    fn make_incremental_table(
+    pub(super) fn make_incremental_table(
         &self,
         region: &mut RegionCtx<'_, N>,
         aux: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         window_size: usize,
     ) -> Result<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
         let table_size = 1 << window_size;
         let mut table = vec![aux.clone()];
         for i in 0..(table_size - 1) {
@@ -71,7 +106,10 @@ impl<
     }
 
     /// Selects a point in > 2 sized table using a selector
-    fn select_multi(
+    /// Selectors shorter than the table size select among the first
+    /// `1 << selector.len()` entries. Returns `Error::Synthesis` if the
+    /// selector is longer than `MAX_WINDOW_SIZE`
+    pub(super) fn select_multi(
         &self,
         region: &mut RegionCtx<'_, N>,
         selector: &Selector<N>,
@@ -79,21 +117,46 @@ impl<
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let number_of_points = table.0.len();
         let number_of_selectors = selector.0.len();
-        assert_eq!(number_of_points, 1 << number_of_selectors);
+        check_window_size(number_of_selectors)?;
+        assert!(number_of_points >= 1 << number_of_selectors);
 
-        let mut reducer = table.0.clone();
+        let mut reducer = table.0[..1 << number_of_selectors].to_vec();
         for (i, selector) in selector.0.iter().enumerate() {
             let n = 1 << (number_of_selectors - 1 - i);
-            for j in 0..n {
-                let k = 2 * j;
-                reducer[j] = self.select(region, selector, &reducer[k + 1], &reducer[k])?;
-            }
+            let (odd, even): (Vec<_>, Vec<_>) = reducer[..2 * n]
+                .chunks(2)
+                .map(|pair| (pair[1].clone(), pair[0].clone()))
+                .unzip();
+            reducer = self.select_batch(region, selector, &odd, &even)?;
         }
         Ok(reducer[0].clone())
     }
 
+    /// Selects a point with a signed selector. Absolute value is selected
+    /// with `select_multi` as for unsigned windows and then the point is
+    /// negated if the sign is set.
+    pub(super) fn select_multi_signed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        selector: &SignedSelector<N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let selected = self.select_multi(region, &selector.bits, table)?;
+        let negated = self.neg(region, &selected)?;
+        self.select(region, &selector.sign, &negated, &selected)
+    }
+
     /// Scalar multiplication of a point in the EC
     /// Performed with the sliding-window algorithm
+    ///
+    /// Layout is oblivious to the witness: scalar is always decomposed into
+    /// `NUM_BITS` bits and every window is selected with `select_multi`, so
+    /// any two scalars take the same rows and constraints. Note that
+    /// `mul_or_identity` takes a shorter path for a scalar assigned as the
+    /// constant zero, which depends on how the scalar is assigned rather than
+    /// its witness value.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
     pub fn mul(
         &self,
         region: &mut RegionCtx<'_, N>,
@@ -101,36 +164,884 @@ impl<
         scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         window_size: usize,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        assert!(window_size > 0);
+        check_window_size(window_size)?;
+        let table = &self.precompute_table(region, point, window_size)?;
+        self.mul_with_table(region, table, scalar, window_size)
+    }
+
+    /// Computes out of circuit the point that `mul` outputs for the given
+    /// auxiliary generator, point, scalar and window size. Steps of `mul` are
+    /// followed: incremental table on top of the auxiliary generator, padded
+    /// windows accumulated from the most significant one and subtraction of
+    /// the auxiliary point assigned with `assign_aux` for a single pair. The
+    /// result is the product for any valid auxiliary generator, so comparing
+    /// it with the witness of `mul` checks the auxiliary setup.
+    pub fn mul_witness(
+        aux_generator: Emulated,
+        point: Emulated,
+        scalar: Emulated::Scalar,
+        window_size: usize,
+    ) -> Emulated {
+        assert!(window_size > 0 && window_size <= MAX_WINDOW_SIZE);
+        let aux_to_sub = make_mul_aux(aux_generator, window_size, 1);
+
+        // table of `aux + i * point`
+        let table: Vec<Emulated::CurveExt> = (0..1usize << window_size)
+            .scan(aux_generator.to_curve(), |entry, _| {
+                let current = *entry;
+                *entry += point.to_curve();
+                Some(current)
+            })
+            .collect();
+
+        // little endian padded bits split into windows from the most
+        // significant one
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        let number_of_windows = (number_of_bits + window_size - 1) / window_size;
+        let scalar = fe_to_big(scalar);
+        let window = |i: usize| {
+            (0..window_size).fold(0usize, |acc, j| {
+                acc | ((scalar.bit((i * window_size + j) as u64) as usize) << j)
+            })
+        };
+
+        let mut acc = table[window(number_of_windows - 1)];
+        for i in (0..number_of_windows - 1).rev() {
+            for _ in 0..window_size {
+                acc = acc.double();
+            }
+            acc += table[window(i)];
+        }
+        (acc + aux_to_sub.to_curve()).to_affine()
+    }
+
+    /// Scalar multiplication with the algorithm chosen by `strategy`. Result
+    /// is the same point for every strategy while rows and witnesses differ.
+    /// `MulStrategy::Balanced` is the same as `mul`.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    /// or if the auxiliary point the strategy requires is not assigned
+    pub fn mul_with_strategy(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+        strategy: MulStrategy,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        match strategy {
+            MulStrategy::MinRows => self.mul_naf(region, point, scalar, window_size),
+            MulStrategy::MinConstraints => self.mul_booth(region, point, scalar, window_size),
+            MulStrategy::Balanced => self.mul(region, point, scalar, window_size),
+        }
+    }
+
+    /// Scalar multiplication where the scalar is given in sign and magnitude
+    /// form. Point is negated if `sign` is set and then multiplied by
+    /// `magnitude` with `mul`. Note that `mul` already interprets a scalar
+    /// modulo the group order, so a negative value assigned as its wrapped
+    /// representative needs no sign.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_signed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        sign: &AssignedCondition<N>,
+        magnitude: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let point = &self.neg_if(region, point, sign)?;
+        self.mul(region, point, magnitude, window_size)
+    }
+
+    /// Same as `mul` and also adds rows of each phase to `profile`. Layout is
+    /// the same as `mul` so the sum of phases added to the profile is the
+    /// offset delta of the multiplication.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_with_profile(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+        profile: &mut MulProfile,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let aux = self.get_mul_aux(window_size, 1)?;
+
+        let offset = region.offset();
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+        profile.make_incremental_table += region.offset() - offset;
+
+        let offset = region.offset();
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+        profile.decompose += region.offset() - offset;
+
+        let (acc, _) = self.accumulate_windowed_with_trace(
+            region,
+            &windowed,
+            window_size,
+            |region, selector| self.select_multi(region, selector, table),
+            Some(profile),
+        )?;
+
+        let offset = region.offset();
+        let result = self.add(region, &acc, &aux.to_sub)?;
+        profile.add += region.offset() - offset;
+        Ok(result)
+    }
+
+    /// Same as `mul` except the auxiliary correction is not applied. Returns
+    /// the accumulator and the correction `to_sub` that `finalize_mul`
+    /// expects. Accumulators of many multiplications under the same
+    /// `window_size` can be summed first and corrected once with the sum of
+    /// their corrections. Since every accumulator carries the same auxiliary
+    /// offset, summing them with `add` fails only if the products are equal.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    #[allow(clippy::type_complexity)]
+    pub fn mul_unfinalized(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<
+        (
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        check_window_size(window_size)?;
+        let aux = self.get_mul_aux(window_size, 1)?;
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+        let acc = self.accumulate_windowed(region, &windowed, table, window_size)?;
+        Ok((acc, aux.to_sub))
+    }
+
+    /// Applies the auxiliary correction to an accumulator found with
+    /// `mul_unfinalized`
+    pub fn finalize_mul(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        acc: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        correction: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.add(region, acc, correction)
+    }
+
+    /// Scalar multiplication of a point in the EC same as `mul` that also
+    /// returns the accumulator at each window boundary starting from the
+    /// most significant window. Accumulators still carry the contribution of
+    /// the auxiliary point except the last one which is replaced with the
+    /// corrected result, so there are as many snapshots as windows. Snapshots
+    /// are the cells assigned by the ladder and no constraint is added.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    #[allow(clippy::type_complexity)]
+    pub fn mul_with_trace(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<
+        (
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Vec<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        ),
+        Error,
+    > {
+        check_window_size(window_size)?;
+        let table = &self.precompute_table(region, point, window_size)?;
+        let aux = self.get_mul_aux(window_size, 1)?;
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+
+        let (acc, mut trace) = self.accumulate_windowed_with_trace(
+            region,
+            &windowed,
+            window_size,
+            |region, selector| self.select_multi(region, selector, table),
+            None,
+        )?;
+        let result = self.add(region, &acc, &aux.to_sub)?;
+        *trace.last_mut().unwrap() = result.clone();
+
+        Ok((result, trace))
+    }
+
+    /// Precomputes the incremental table of a point so that it can be reused
+    /// with `mul_with_table` for many scalars. Table is built on top of the
+    /// auxiliary point currently assigned for `window_size`.
+    pub fn precompute_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let aux = self.get_mul_aux(window_size, 1)?;
+        self.make_incremental_table(region, &aux.to_add, point, window_size)
+    }
+
+    /// Scalar multiplication of a point in the EC using a table constructed
+    /// with `precompute_table` under the same `window_size`
+    pub fn mul_with_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        // table is built for another window size
+        if table.0.len() != 1 << window_size {
+            return Err(Error::Synthesis);
+        }
         let aux = self.get_mul_aux(window_size, 1)?;
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+        self.mul_windowed(region, &windowed, table, &aux.to_sub, window_size)
+    }
 
+    /// Decomposes a scalar into bits, pads them and splits them into windows
+    /// as `mul` does. Result can be passed to `mul_with_windows` many times
+    /// so that a scalar used in several multiplications is decomposed once.
+    pub fn decompose_scalar_into_windows(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Windowed<N>, Error> {
+        check_window_size(window_size)?;
         let scalar_chip = self.scalar_field_chip();
-        let decomposed = &mut scalar_chip.decompose(region, scalar)?;
-        self.pad(region, decomposed, window_size)?;
-        let windowed = Self::window(decomposed.to_vec(), window_size);
+        let mut decomposed = scalar_chip.decompose(region, scalar)?;
+        self.pad(region, &mut decomposed, window_size)?;
+        Ok(Self::window(decomposed, window_size))
+    }
+
+    /// Returns an error if the windowed scalar is not the one that
+    /// `decompose_scalar_into_windows` returns for `window_size`
+    fn check_windowed(windowed: &Windowed<N>, window_size: usize) -> Result<(), Error> {
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        let number_of_windows = (number_of_bits + window_size - 1) / window_size;
+        // auxiliary point is computed for this number of windows
+        if windowed.0.len() != number_of_windows
+            || windowed
+                .0
+                .iter()
+                .any(|selector| selector.0.len() != window_size)
+        {
+            return Err(Error::Synthesis);
+        }
+        Ok(())
+    }
+
+    /// Scalar multiplication of a point in the EC where the scalar is already
+    /// decomposed with `decompose_scalar_into_windows` under the same
+    /// `window_size`. Returns an error if the number of windows doesn't match.
+    pub fn mul_with_windows(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        windowed: &Windowed<N>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        Self::check_windowed(windowed, window_size)?;
+
+        let aux = self.get_mul_aux(window_size, 1)?;
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+        self.mul_windowed(region, windowed, table, &aux.to_sub, window_size)
+    }
+
+    /// Scalar multiplication of a point in the EC where the scalar is a
+    /// public input. Little endian bits of the scalar, as
+    /// `public_scalar_bits` returns them, are expected in the instance column
+    /// starting from the row `instance_offset`. Bits are copied from the
+    /// instance so the scalar is not decomposed and the bits are not
+    /// constrained to be booleans, it is up to the verifier to provide a
+    /// valid decomposition.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_public_scalar(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        instance_offset: usize,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let main_gate = self.main_gate();
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        let mut bits = (instance_offset..instance_offset + number_of_bits)
+            .map(|row| main_gate.assign_from_instance(region, row))
+            .collect::<Result<Vec<AssignedCondition<N>>, Error>>()?;
+        self.pad(region, &mut bits, window_size)?;
+        let windowed = Self::window(bits, window_size);
+        self.mul_with_windows(region, point, &windowed, window_size)
+    }
+
+    /// Scalar multiplication of a point in the EC where the scalar is given as
+    /// little endian bits, for example decomposed by another gadget, so that
+    /// it is not decomposed again. Bits are not constrained to be booleans,
+    /// it is up to the caller. Fewer bits than `NUM_BITS` of the scalar field
+    /// are extended with zeros so the higher bits of the scalar are zero and
+    /// then bits are padded and windowed as in `mul` with the same auxiliary
+    /// point. Bits that compose a value larger than the order of the curve
+    /// multiply with the value reduced by the order.
+    ///
+    /// Panics if more than `NUM_BITS` bits are given. Returns
+    /// `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_from_bits(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        bits: &[AssignedCondition<N>],
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        assert!(bits.len() <= number_of_bits);
+
+        // the same zero extends the scalar and pads the windows
+        let zero = if bits.len() < number_of_bits {
+            Some(self.main_gate().assign_constant(region, N::ZERO)?)
+        } else {
+            self.padding_zero(region, window_size)?
+        };
+        let mut bits = bits.to_vec();
+        if let Some(zero) = &zero {
+            bits.resize(number_of_bits, zero.clone());
+        }
+        Self::pad_with(&mut bits, zero.as_ref(), window_size);
+        let windowed = Self::window(bits, window_size);
+        self.mul_with_windows(region, point, &windowed, window_size)
+    }
+
+    /// Returns little endian bits of the scalar that `mul_public_scalar`
+    /// expects in the instance column
+    pub fn public_scalar_bits(scalar: Emulated::ScalarExt) -> Vec<N> {
+        let scalar = fe_to_big(scalar);
+        (0..Emulated::ScalarExt::NUM_BITS as u64)
+            .map(|i| if scalar.bit(i) { N::ONE } else { N::ZERO })
+            .collect()
+    }
+
+    /// Scalar multiplication of a point in the EC
+    /// Unlike `mul` scalar bits are not padded with zeros, instead the most
+    /// significant window is shorter when the bit length of the scalar field
+    /// is not a multiple of `window_size`. Selection in the ending window only
+    /// touches the prefix of the table that it can reach.
+    pub fn mul_unpadded(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        // Number of windows is the same with the padded approach so that the
+        // same auxiliary points are used
+        let aux = self.get_mul_aux(window_size, 1)?;
+
+        let scalar_chip = self.scalar_field_chip();
+        let decomposed = scalar_chip.decompose(region, scalar)?;
+        let windowed = Self::window_unpadded(&decomposed, window_size);
         let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
 
-        let mut acc = self.select_multi(region, &windowed.0[0], table)?;
+        self.mul_windowed(region, &windowed, table, &aux.to_sub, window_size)
+    }
+
+    /// Scalar multiplication of a point in the EC where the scalar is known
+    /// to fit in `max_bits` bits. Limbs of the scalar are decomposed with the
+    /// main gate only up to `max_bits` bits and the limbs above are
+    /// constrained to be zero, so a larger scalar makes the circuit
+    /// unsatisfiable. Only `max_bits` bits are windowed so that the ladder
+    /// runs over fewer windows than `mul`.
+    ///
+    /// Composed limbs are not constrained to be in the scalar field. Value
+    /// they compose is congruent to the scalar so the result is the same
+    /// point. Scalar is reduced first if its tracked bounds don't show that
+    /// the limbs fit in `BIT_LEN_LIMB` bits.
+    ///
+    /// Returns `Error::Synthesis` if `max_bits` exceeds the bit length of the
+    /// scalar field or doesn't span more than a single window.
+    ///
+    /// Auxiliary point must be assigned with `assign_aux_bounded` for the
+    /// `window_size` and `max_bits`.
+    pub fn mul_bounded(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        max_bits: usize,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        // at least two windows are expected by the ladder
+        if max_bits > Emulated::ScalarExt::NUM_BITS as usize || max_bits <= window_size {
+            return Err(Error::Synthesis);
+        }
+        let aux = self.get_mul_aux_bounded(window_size, max_bits)?;
+
+        let main_gate = self.main_gate();
+        let scalar = &if scalar.has_reduced_limbs() {
+            scalar.clone()
+        } else {
+            self.scalar_field_chip().reduce(region, scalar)?
+        };
+        let mut bits = vec![];
+        for (idx, limb) in scalar.limb_values().iter().enumerate() {
+            let number_of_bits = BIT_LEN_LIMB.min(max_bits.saturating_sub(idx * BIT_LEN_LIMB));
+            if number_of_bits == 0 {
+                main_gate.assert_zero(region, limb)?;
+            } else {
+                bits.extend(main_gate.to_bits(region, limb, number_of_bits)?);
+            }
+        }
+
+        self.mul_bits(region, point, bits, &aux, window_size)
+    }
+
+    /// Scalar multiplication of a point in the EC where the scalar is an
+    /// element of the native field. Scalar is decomposed into `N::NUM_BITS`
+    /// bits with the main gate and the bits are constrained to be less than
+    /// the native modulus, so the decomposition is unique. Result is the
+    /// point multiplied with the scalar reduced modulo the order of the curve.
+    ///
+    /// Auxiliary point must be assigned with `assign_aux_bounded` for the
+    /// `window_size` and `N::NUM_BITS` bits.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    pub fn mul_native_scalar(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedValue<N>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let number_of_bits = N::NUM_BITS as usize;
+        let aux = self.get_mul_aux_bounded(window_size, number_of_bits)?;
+
+        let bits = self.main_gate().to_bits(region, scalar, number_of_bits)?;
+        self.assert_native_bits_in_field(region, &bits)?;
+
+        self.mul_bits(region, point, bits, &aux, window_size)
+    }
+
+    /// Constraints that little endian bits of `N::NUM_BITS` length compose a
+    /// value that is less than the native modulus. Bits are compared with the
+    /// bits of `modulus - 1` starting from the most significant one where
+    /// `eq` is set while the prefix is equal and `gt` once it is greater.
+    fn assert_native_bits_in_field(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        bits: &[AssignedCondition<N>],
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let max = fe_to_big(-N::ONE);
+
+        // `None` stands for the constant one of `eq` and zero of `gt`
+        let mut eq: Option<AssignedCondition<N>> = None;
+        let mut gt: Option<AssignedCondition<N>> = None;
+        for (i, bit) in bits.iter().enumerate().rev() {
+            if max.bit(i as u64) {
+                eq = Some(match eq {
+                    Some(eq) => main_gate.and(region, &eq, bit)?,
+                    None => bit.clone(),
+                });
+            } else {
+                let greater = match &eq {
+                    Some(eq) => main_gate.and(region, eq, bit)?,
+                    None => bit.clone(),
+                };
+                gt = Some(match gt {
+                    Some(gt) => main_gate.or(region, &gt, &greater)?,
+                    None => greater,
+                });
+                let not_bit = main_gate.not(region, bit)?;
+                eq = Some(match eq {
+                    Some(eq) => main_gate.and(region, &eq, &not_bit)?,
+                    None => not_bit,
+                });
+            }
+        }
+
+        match gt {
+            Some(gt) => main_gate.assert_zero(region, &gt),
+            None => Ok(()),
+        }
+    }
+
+    /// Multiplies the point with little endian bits of a scalar where
+    /// auxiliary point is computed for the number of bits
+    fn mul_bits(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        bits: Vec<AssignedCondition<N>>,
+        aux: &MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let windowed = self.window_bits(region, bits, window_size)?;
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+        self.mul_windowed(region, &windowed, table, &aux.to_sub, window_size)
+    }
+
+    /// Pads little endian bits of a scalar with zeros up to the next
+    /// window_size mul and splits them into windows most significant first
+    pub(super) fn window_bits(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        mut bits: Vec<AssignedCondition<N>>,
+        window_size: usize,
+    ) -> Result<Windowed<N>, Error> {
+        // pad up to the next window_size mul
+        let number_of_bits = bits.len();
+        let padding_offset = (window_size - (number_of_bits % window_size)) % window_size;
+        if padding_offset > 0 {
+            let zero = self.main_gate().assign_constant(region, N::ZERO)?;
+            bits.extend(std::iter::repeat(zero).take(padding_offset));
+        }
+        bits.reverse();
+        Ok(Self::window(bits, window_size))
+    }
+
+    /// Accumulates windowed scalar over the incremental table and subtracts
+    /// the auxiliary contribution. First window can be shorter than the
+    /// others.
+    fn mul_windowed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        windowed: &Windowed<N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        to_sub: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let acc = self.accumulate_windowed(region, windowed, table, window_size)?;
+        self.add(region, &acc, to_sub)
+    }
+
+    /// Scalar multiplication of a point in the EC that returns the identity
+    /// if the scalar is zero. Same as `mul` except the auxiliary contribution
+    /// is subtracted with complete addition.
+    ///
+    /// If the scalar is known to be zero at synthesis time, for example it is
+    /// assigned with `assign_constant`, the identity is assigned directly and
+    /// the ladder is skipped.
+    pub fn mul_or_identity(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        check_window_size(window_size)?;
+        if scalar.is_known_zero() {
+            return self.assign_identity(region);
+        }
+        let aux = self.get_mul_aux(window_size, 1)?;
+
+        let windowed = self.decompose_scalar_into_windows(region, scalar, window_size)?;
+        let table = &self.make_incremental_table(region, &aux.to_add, point, window_size)?;
+
+        let acc = self.accumulate_windowed(region, &windowed, table, window_size)?;
+        let acc = &self.to_point_or_identity(region, &acc)?;
+        let to_sub = &self.to_point_or_identity(region, &aux.to_sub)?;
+        self.add_complete(region, acc, to_sub)
+    }
+
+    /// Accumulates windowed scalar over the incremental table. Result still
+    /// contains the auxiliary contribution.
+    fn accumulate_windowed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        windowed: &Windowed<N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.accumulate_windowed_with(region, windowed, window_size, |region, selector| {
+            self.select_multi(region, selector, table)
+        })
+    }
+
+    /// Accumulates windowed scalar where table entries are selected with the
+    /// given function
+    pub(super) fn accumulate_windowed_with(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        windowed: &Windowed<N>,
+        window_size: usize,
+        mut select: impl FnMut(
+            &mut RegionCtx<'_, N>,
+            &Selector<N>,
+        ) -> Result<
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Error,
+        >,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.accumulate_windowed_with_trace(region, windowed, window_size, select, None)
+            .map(|(acc, _)| acc)
+    }
+
+    /// Same as `accumulate_windowed_with` and also returns the accumulator
+    /// after each window. Accumulator of a window is the one before the
+    /// doublings of the next window. Rows of each phase are added to
+    /// `profile` if it is given.
+    #[allow(clippy::type_complexity)]
+    fn accumulate_windowed_with_trace(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        windowed: &Windowed<N>,
+        window_size: usize,
+        mut select: impl FnMut(
+            &mut RegionCtx<'_, N>,
+            &Selector<N>,
+        ) -> Result<
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Error,
+        >,
+        mut profile: Option<&mut MulProfile>,
+    ) -> Result<
+        (
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Vec<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        ),
+        Error,
+    > {
+        let mut record = |phase: fn(&mut MulProfile) -> &mut usize, rows: usize| {
+            if let Some(profile) = profile.as_deref_mut() {
+                *phase(profile) += rows;
+            }
+        };
+
+        let offset = region.offset();
+        let mut acc = select(region, &windowed.0[0])?;
+        record(
+            |profile| &mut profile.select_multi,
+            region.offset() - offset,
+        );
+        let mut trace = vec![acc.clone()];
+        let offset = region.offset();
         acc = self.double_n(region, &acc, window_size)?;
+        record(|profile| &mut profile.double_n, region.offset() - offset);
 
-        let to_add = self.select_multi(region, &windowed.0[1], table)?;
+        let offset = region.offset();
+        let to_add = select(region, &windowed.0[1])?;
+        record(
+            |profile| &mut profile.select_multi,
+            region.offset() - offset,
+        );
+        let offset = region.offset();
         acc = self.add(region, &acc, &to_add)?;
+        record(|profile| &mut profile.add, region.offset() - offset);
+        trace.push(acc.clone());
 
         for selector in windowed.0.iter().skip(2) {
+            let offset = region.offset();
             acc = self.double_n(region, &acc, window_size - 1)?;
-            let to_add = self.select_multi(region, selector, table)?;
+            record(|profile| &mut profile.double_n, region.offset() - offset);
+            let offset = region.offset();
+            let to_add = select(region, selector)?;
+            record(
+                |profile| &mut profile.select_multi,
+                region.offset() - offset,
+            );
+            let offset = region.offset();
+            acc = self.ladder(region, &acc, &to_add)?;
+            record(|profile| &mut profile.ladder, region.offset() - offset);
+            trace.push(acc.clone());
+        }
+
+        Ok((acc, trace))
+    }
+
+    /// Constructs table of odd multiples of the point
+    /// `[P, 3P, 5P, ..., (2^window_size - 1)P]` to be used in signed digit
+    /// multiplication
+    fn make_odd_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let table_size = 1 << (window_size - 1);
+        let mut table = vec![point.clone()];
+        if table_size > 1 {
+            let double = self.double(region, point)?;
+            for i in 0..(table_size - 1) {
+                table.push(self.add(region, &table[i], &double)?);
+            }
+        }
+        Ok(Table(table))
+    }
+
+    /// Selects a signed odd multiple of a point from the odd table. Negation is
+    /// applied if `sign` is zero and in that case selector bits are inverted.
+    fn select_signed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        bits: &[AssignedCondition<N>],
+        sign: &AssignedCondition<N>,
+        table: &Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let selector = bits
+            .iter()
+            .map(|bit| {
+                let bit = main_gate.xor(region, bit, sign)?;
+                main_gate.not(region, &bit)
+            })
+            .collect::<Result<Vec<AssignedCondition<N>>, Error>>()?;
+        let selected = self.select_multi(region, &Selector(selector), table)?;
+        let negated = self.neg(region, &selected)?;
+        self.select(region, sign, &selected, &negated)
+    }
+
+    /// Scalar multiplication of a point in the EC
+    /// Performed with signed odd digits in a regular w-NAF like recoding.
+    ///
+    /// An odd scalar `k` with bits `b_i` can be written as
+    /// `k = 2^(n-1) + sum_{i < n-1} (2 * b_{i+1} - 1) * 2^i`, so that each window
+    /// of `window_size` signed digits is an odd value in
+    /// `[-(2^window_size - 1), 2^window_size - 1]`. Only odd multiples of the
+    /// point are required in the table which halves its size. Absolute value
+    /// of a window is selected with the bits of the window xnored with the sign
+    /// and selected point is conditionally negated. Even scalars are handled
+    /// by subtracting the point at the end.
+    ///
+    /// Since auxiliary generator is added only once, `assign_aux_naf` must be
+    /// called for the `window_size` before using this function.
+    pub fn mul_naf(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        let aux = self.get_mul_aux_naf(window_size)?;
+
+        let scalar_chip = self.scalar_field_chip();
+        let decomposed = &mut scalar_chip.decompose(region, scalar)?;
+        let is_odd = decomposed[0].clone();
+        // little endian padded bits
+        self.pad(region, decomposed, window_size)?;
+        decomposed.reverse();
+        let number_of_windows = decomposed.len() / window_size;
+
+        let table = &self.make_odd_table(region, point, window_size)?;
+
+        // windows are counted from the least significant one
+        let window = |i: usize| &decomposed[i * window_size + 1..(i + 1) * window_size];
+
+        // most significant signed digit is always positive
+        let selector = Selector(window(number_of_windows - 1).to_vec());
+        let mut acc = self.select_multi(region, &selector, table)?;
+        acc = self.add(region, &acc, &aux.to_add)?;
+        for i in (0..number_of_windows - 1).rev() {
+            acc = self.double_n(region, &acc, window_size - 1)?;
+            let sign = &decomposed[(i + 1) * window_size];
+            let to_add = self.select_signed(region, window(i), sign, table)?;
             acc = self.ladder(region, &acc, &to_add)?;
         }
 
+        // recoding computes multiplication with `k + 1` for even `k`
+        let point_neg = self.neg(region, point)?;
+        let acc_even = self.add(region, &acc, &point_neg)?;
+        let acc = self.select(region, &is_odd, &acc, &acc_even)?;
+
         self.add(region, &acc, &aux.to_sub)
     }
 
+    /// Merges pairs that share the same assigned point so that a single table
+    /// is built for each distinct point. Scalars of a repeated point are
+    /// summed. Points are compared by their cells so that the layout does not
+    /// depend on witness values. Pairs are returned as they are if auxiliary
+    /// point is not assigned for the number of distinct points, so that a
+    /// batch set up for the number of pairs still works.
+    #[allow(clippy::type_complexity)]
+    fn merge_duplicate_points(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        pairs: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        window_size: usize,
+    ) -> Result<
+        Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        Error,
+    > {
+        let number_of_distinct_points = pairs
+            .iter()
+            .enumerate()
+            .filter(|(i, (point, _))| {
+                !pairs[..*i]
+                    .iter()
+                    .any(|(other, _)| other.shares_cells(point))
+            })
+            .count();
+        if number_of_distinct_points == pairs.len()
+            || self
+                .get_mul_aux(window_size, number_of_distinct_points)
+                .is_err()
+        {
+            return Ok(pairs);
+        }
+
+        let scalar_chip = self.scalar_field_chip();
+        let mut merged: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )> = vec![];
+        for (point, scalar) in pairs.into_iter() {
+            match merged
+                .iter()
+                .position(|(other, _)| other.shares_cells(&point))
+            {
+                Some(i) => {
+                    let sum = scalar_chip.add(region, &merged[i].1, &scalar)?;
+                    // decomposition expects a reduced scalar
+                    merged[i].1 = scalar_chip.reduce(region, &sum)?;
+                }
+                None => merged.push((point, scalar)),
+            }
+        }
+        Ok(merged)
+    }
+
     /// Computes multi-product
     ///
     /// Given a vector of point, scalar pairs
     /// `[(P_0, e_0), (P_1, e_1), ..., (P_k, e_k)] `
     /// Returns:
     /// `P_0 * e_0 + P_1 * e_1 + ...+ P_k * e_k`
+    ///
+    /// Pairs that use the same assigned point are merged if auxiliary point
+    /// is assigned for the number of distinct points with `assign_aux` or
+    /// `assign_aux_up_to`, otherwise it must be assigned for the number of
+    /// pairs and every pair gets its own table.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    /// or if there are no pairs since the identity is not an `AssignedPoint`.
+    /// See `mul_batch_or_identity` for batches that can be empty. Returns
+    /// [`RowLimitExceeded`] if the batch goes past the limit set with
+    /// `set_max_rows`.
+    ///
+    /// [`RowLimitExceeded`]: crate::RowLimitExceeded
     #[allow(clippy::type_complexity)]
     pub fn mul_batch_1d_horizontal(
         &self,
@@ -141,9 +1052,11 @@ impl<
         )>,
         window_size: usize,
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        assert!(window_size > 0);
-        assert!(!pairs.is_empty());
-        let aux = self.get_mul_aux(window_size, pairs.len())?;
+        if pairs.is_empty() {
+            return Err(Error::Synthesis);
+        }
+        check_window_size(window_size)?;
+        let pairs = self.merge_duplicate_points(region, pairs, window_size)?;
 
         let scalar_chip = self.scalar_field_chip();
         // 1. Decompose scalars in bits
@@ -152,31 +1065,272 @@ impl<
             .map(|(_, scalar)| scalar_chip.decompose(region, scalar))
             .collect::<Result<_, Error>>()?;
 
-        // 2. Pad scalars bit representations
+        // 2. Pad scalars bit representations sharing the same zero
+        let zero = self.padding_zero(region, window_size)?;
         for decomposed in decomposed_scalars.iter_mut() {
-            self.pad(region, decomposed, window_size)?;
+            Self::pad_with(decomposed, zero.as_ref(), window_size);
         }
 
         // 3. Split scalar bits into windows
-        let windowed_scalars: Vec<Windowed<N>> = decomposed_scalars
+        let pairs = pairs
             .into_iter()
-            .map(|decomposed| Self::window(decomposed, window_size))
+            .zip(decomposed_scalars.into_iter())
+            .map(|((point, _), decomposed)| (point, Self::window(decomposed, window_size)))
             .collect();
-        let number_of_windows = windowed_scalars[0].0.len();
 
+        self.mul_batch_tables(region, pairs, window_size, "mul_batch_1d_horizontal")
+    }
+
+    /// Computes multi-product as `mul_batch_1d_horizontal` does and returns
+    /// the identity for an empty batch, in which case only the identity is
+    /// assigned. Result of a non empty batch is never the identity.
+    #[allow(clippy::type_complexity)]
+    pub fn mul_batch_or_identity(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        pairs: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        window_size: usize,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        if pairs.is_empty() {
+            return self.assign_identity(region);
+        }
+        let result = self.mul_batch_1d_horizontal(region, pairs, window_size)?;
+        self.to_point_or_identity(region, &result)
+    }
+
+    /// Computes multi-product as `mul_batch_1d_horizontal` does while only a
+    /// single table is alive at a time. Each pair runs its own ladder over
+    /// its table and the results are summed, so that the auxiliary terms add
+    /// up to the same value as in the batch and the same auxiliary point is
+    /// used. Doublings are repeated for every pair rather than shared, so it
+    /// costs more rows than `mul_batch_1d_horizontal`.
+    ///
+    /// Returns `Error::Synthesis` if `window_size` exceeds `MAX_WINDOW_SIZE`
+    /// or if there are no pairs.
+    #[allow(clippy::type_complexity)]
+    pub fn msm_streaming(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        pairs: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if pairs.is_empty() {
+            return Err(Error::Synthesis);
+        }
+        check_window_size(window_size)?;
+        let pairs = self.merge_duplicate_points(region, pairs, window_size)?;
+        let aux = self.get_mul_aux(window_size, pairs.len())?;
+
+        let number_of_pairs = pairs.len();
         let mut binary_aux = aux.to_add.clone();
+        let mut acc: Option<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = None;
+        for (i, (point, scalar)) in pairs.into_iter().enumerate() {
+            let windowed = self.decompose_scalar_into_windows(region, &scalar, window_size)?;
+            let table = self.make_incremental_table(region, &binary_aux, &point, window_size)?;
+            let contribution = self.accumulate_windowed(region, &windowed, &table, window_size)?;
+            acc = Some(match acc {
+                Some(acc) => self.add(region, &acc, &contribution)?,
+                None => contribution,
+            });
+            if i != number_of_pairs - 1 {
+                binary_aux = self.double(region, &binary_aux)?;
+            }
+        }
+
+        self.add(region, &acc.unwrap(), &aux.to_sub)
+    }
+
+    /// Computes multi-product as `mul_batch_1d_horizontal` does where scalars
+    /// are already decomposed with `decompose_scalar_into_windows` under the
+    /// same `window_size`. Pairs are not merged so auxiliary point must be
+    /// assigned for the number of pairs. Returns an error if a windowed scalar
+    /// doesn't match the window size, if there are no pairs or if the batch
+    /// goes past the limit set with `set_max_rows`.
+    #[allow(clippy::type_complexity)]
+    pub fn mul_batch_with_windows(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        pairs: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Windowed<N>,
+        )>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.mul_batch_tables(region, pairs, window_size, "mul_batch_with_windows")
+    }
+
+    /// Builds a table for each pair and accumulates the windowed scalars over
+    /// them. Row limit is checked after each table for the `operation`.
+    #[allow(clippy::type_complexity)]
+    fn mul_batch_tables(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        pairs: Vec<(
+            AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            Windowed<N>,
+        )>,
+        window_size: usize,
+        operation: &'static str,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(window_size)?;
+        if pairs.is_empty() {
+            return Err(Error::Synthesis);
+        }
+        for (_, windowed) in pairs.iter() {
+            Self::check_windowed(windowed, window_size)?;
+        }
+        let aux = self.get_mul_aux(window_size, pairs.len())?;
+
+        let binary_aux = self.binary_aux(region, &aux.to_add, pairs.len())?;
         let tables: Vec<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = pairs
             .iter()
-            .enumerate()
-            .map(|(i, (point, _))| {
-                let table = self.make_incremental_table(region, &binary_aux, point, window_size);
-                if i != pairs.len() - 1 {
-                    binary_aux = self.double(region, &binary_aux)?;
-                }
-                table
+            .zip(binary_aux.iter())
+            .map(|((point, _), binary_aux)| {
+                let table = self.make_incremental_table(region, binary_aux, point, window_size)?;
+                self.check_max_rows(region, operation)?;
+                Ok(table)
             })
             .collect::<Result<_, Error>>()?;
 
+        let windowed_scalars: Vec<Windowed<N>> =
+            pairs.into_iter().map(|(_, windowed)| windowed).collect();
+        self.mul_batch_windowed(
+            region,
+            &tables,
+            &windowed_scalars,
+            &aux.to_sub,
+            window_size,
+            operation,
+        )
+    }
+
+    /// Returns `[aux, 2 * aux, ..., 2^(number_of_pairs - 1) * aux]` that the
+    /// tables of a batch are built on. Doublings are made before any table is
+    /// constructed so the same values can be reused for another batch of
+    /// the same size.
+    pub(super) fn binary_aux(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        aux: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        number_of_pairs: usize,
+    ) -> Result<Vec<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error> {
+        let mut binary_aux = vec![aux.clone()];
+        for i in 1..number_of_pairs {
+            binary_aux.push(self.double(region, &binary_aux[i - 1])?);
+        }
+        Ok(binary_aux)
+    }
+
+    /// Constructs the combined table of two points where the entry at index
+    /// `i + j * 2^window_size` is `aux + i * p_1 + j * p_2`
+    fn make_combined_table(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        aux: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p_1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p_2: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let mut row = self
+            .make_incremental_table(region, aux, p_1, window_size)?
+            .0;
+        let mut table = row.clone();
+        for _ in 1..(1 << window_size) {
+            row = row
+                .iter()
+                .map(|point| self.add(region, point, p_2))
+                .collect::<Result<_, Error>>()?;
+            table.extend(row.iter().cloned());
+        }
+        Ok(Table(table))
+    }
+
+    /// Computes `s_1 * p_1 + s_2 * p_2` with Shamir's trick. A single table of
+    /// both points is indexed by windows of both scalars, so that a selection
+    /// and an addition is made per window rather than one per pair as in
+    /// `mul_batch_1d_horizontal`. Auxiliary point is added once per window as
+    /// it is in `mul`, so auxiliary point must be assigned for a single pair
+    /// with the `window_size`.
+    ///
+    /// Table has `2^(2 * window_size)` entries so it pays off only for small
+    /// window sizes. Returns `Error::Synthesis` if `2 * window_size` exceeds
+    /// `MAX_WINDOW_SIZE`
+    pub fn mul_double(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        p_1: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        s_1: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p_2: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        s_2: &AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        check_window_size(2 * window_size)?;
+        let aux = self.get_mul_aux(window_size, 1)?;
+
+        let scalar_chip = self.scalar_field_chip();
+        let zero = self.padding_zero(region, window_size)?;
+        let windowed_scalars = [s_1, s_2]
+            .iter()
+            .map(|scalar| {
+                let mut decomposed = scalar_chip.decompose(region, scalar)?;
+                Self::pad_with(&mut decomposed, zero.as_ref(), window_size);
+                Ok(Self::window(decomposed, window_size))
+            })
+            .collect::<Result<Vec<Windowed<N>>, Error>>()?;
+
+        // little endian selector bits of both windows index the combined table
+        let windowed = Windowed(
+            windowed_scalars[0]
+                .0
+                .iter()
+                .zip(windowed_scalars[1].0.iter())
+                .map(|(selector_1, selector_2)| {
+                    Selector(
+                        selector_1
+                            .0
+                            .iter()
+                            .chain(selector_2.0.iter())
+                            .cloned()
+                            .collect(),
+                    )
+                })
+                .collect(),
+        );
+
+        let table = &self.make_combined_table(region, &aux.to_add, p_1, p_2, window_size)?;
+        self.mul_windowed(region, &windowed, table, &aux.to_sub, window_size)
+    }
+
+    /// Accumulates windowed scalars over their tables in batch multiplication
+    /// and subtracts the auxiliary contribution. Returns an error if scalars
+    /// are not split into the same number of windows. Row limit is checked
+    /// after each window and after the subtraction for the `operation`.
+    pub(super) fn mul_batch_windowed(
+        &self,
+        region: &mut RegionCtx<'_, N>,
+        tables: &[Table<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        windowed_scalars: &[Windowed<N>],
+        to_sub: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+        operation: &'static str,
+    ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert_eq!(tables.len(), windowed_scalars.len());
+        let number_of_windows = windowed_scalars[0].0.len();
+        // zipping windows would silently truncate the longer decompositions
+        if windowed_scalars
+            .iter()
+            .any(|windowed| windowed.0.len() != number_of_windows)
+        {
+            return Err(Error::Synthesis);
+        }
+
         // preparation for the first round
         // initialize accumulator
         let mut acc = self.select_multi(region, &windowed_scalars[0].0[0], &tables[0])?;
@@ -186,6 +1340,7 @@ impl<
             let to_add = self.select_multi(region, selector, table)?;
             acc = self.add(region, &acc, &to_add)?;
         }
+        self.check_max_rows(region, operation)?;
 
         for i in 1..number_of_windows {
             acc = self.double_n(region, &acc, window_size)?;
@@ -194,8 +1349,11 @@ impl<
                 let to_add = self.select_multi(region, selector, table)?;
                 acc = self.add(region, &acc, &to_add)?;
             }
+            self.check_max_rows(region, operation)?;
         }
 
-        self.add(region, &acc, &aux.to_sub)
+        let result = self.add(region, &acc, to_sub)?;
+        self.check_max_rows(region, operation)?;
+        Ok(result)
     }
 }
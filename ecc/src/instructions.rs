@@ -0,0 +1,239 @@
+use crate::halo2::arithmetic::CurveAffine;
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::ff::PrimeField;
+use crate::halo2::plonk::Error;
+use crate::integer::AssignedInteger;
+use crate::maingate::{AssignedCondition, AssignedValue, RegionCtx};
+use crate::{AssignedPoint, BaseFieldEccChip, GeneralEccChip};
+
+/// Common set of elliptic curve operations of [`GeneralEccChip`] and
+/// [`BaseFieldEccChip`] so that gadgets can be written once for either chip.
+/// `Emulated` is the curve that is constrained over the native field `N`.
+pub trait EccInstructions<Emulated: CurveAffine, N: PrimeField> {
+    /// Assigned point on the emulated curve
+    type AssignedPoint: Clone;
+    /// Assigned scalar that points are multiplied with
+    type AssignedScalar: Clone;
+
+    /// Takes `Point` of the EC and returns it as `AssignedPoint`. Assigned
+    /// point is constrained to be on the curve
+    fn assign_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<Emulated>,
+    ) -> Result<Self::AssignedPoint, Error>;
+
+    /// Constraints to ensure `AssignedPoint` is on curve
+    fn assert_is_on_curve(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &Self::AssignedPoint,
+    ) -> Result<(), Error>;
+
+    /// Constraints assert two `AssignedPoint`s are equal
+    fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<(), Error>;
+
+    /// Selects `p1` if `c` is set and `p2` otherwise
+    fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &Self::AssignedPoint,
+        p2: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error>;
+
+    /// Adds 2 distinct `AssignedPoint`s
+    fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error>;
+
+    /// Doubles an `AssignedPoint`
+    fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error>;
+
+    /// Returns the negative of an `AssignedPoint`
+    fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error>;
+
+    /// Scalar multiplication of a point in the EC. Auxiliary point for the
+    /// `window_size` must be assigned beforehand
+    fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &Self::AssignedPoint,
+        scalar: &Self::AssignedScalar,
+        window_size: usize,
+    ) -> Result<Self::AssignedPoint, Error>;
+}
+
+impl<
+        Emulated: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > EccInstructions<Emulated, N> for GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    type AssignedPoint = AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+    type AssignedScalar = AssignedInteger<Emulated::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+
+    fn assign_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<Emulated>,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::assign_point(self, ctx, point)
+    }
+
+    fn assert_is_on_curve(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &Self::AssignedPoint,
+    ) -> Result<(), Error> {
+        GeneralEccChip::assert_is_on_curve(self, ctx, point)
+    }
+
+    fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<(), Error> {
+        GeneralEccChip::assert_equal(self, ctx, p0, p1)
+    }
+
+    fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &Self::AssignedPoint,
+        p2: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::select(self, ctx, c, p1, p2)
+    }
+
+    fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::add(self, ctx, p0, p1)
+    }
+
+    fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::double(self, ctx, p)
+    }
+
+    fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::neg(self, ctx, p)
+    }
+
+    fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &Self::AssignedPoint,
+        scalar: &Self::AssignedScalar,
+        window_size: usize,
+    ) -> Result<Self::AssignedPoint, Error> {
+        GeneralEccChip::mul(self, ctx, point, scalar, window_size)
+    }
+}
+
+impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    EccInstructions<C, C::Scalar> for BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    type AssignedPoint = AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+    type AssignedScalar = AssignedValue<C::Scalar>;
+
+    fn assign_point(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: Value<C>,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::assign_point(self, ctx, point)
+    }
+
+    fn assert_is_on_curve(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: &Self::AssignedPoint,
+    ) -> Result<(), Error> {
+        BaseFieldEccChip::assert_is_on_curve(self, ctx, point)
+    }
+
+    fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<(), Error> {
+        BaseFieldEccChip::assert_equal(self, ctx, p0, p1)
+    }
+
+    fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        c: &AssignedCondition<C::Scalar>,
+        p1: &Self::AssignedPoint,
+        p2: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::select(self, ctx, c, p1, p2)
+    }
+
+    fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p0: &Self::AssignedPoint,
+        p1: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::add(self, ctx, p0, p1)
+    }
+
+    fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::double(self, ctx, p)
+    }
+
+    fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p: &Self::AssignedPoint,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::neg(self, ctx, p)
+    }
+
+    fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: &Self::AssignedPoint,
+        scalar: &Self::AssignedScalar,
+        window_size: usize,
+    ) -> Result<Self::AssignedPoint, Error> {
+        BaseFieldEccChip::mul(self, ctx, point, scalar, window_size)
+    }
+}
@@ -5,11 +5,14 @@
 
 pub use base_field_ecc::*;
 pub use general_ecc::*;
+pub use instructions::EccInstructions;
 
 /// Constraints for the SW curve that are used in the same proof system
 pub mod base_field_ecc;
 /// Constaints for any SW curve
 pub mod general_ecc;
+/// Elliptic curve operations common to both chips
+pub mod instructions;
 
 pub use integer;
 pub use integer::halo2;
@@ -18,12 +21,16 @@ pub use integer::maingate;
 #[cfg(test)]
 use halo2::halo2curves as curves;
 
-use crate::halo2::arithmetic::CurveAffine;
+use crate::halo2::arithmetic::{CurveAffine, CurveExt};
 use crate::integer::chip::IntegerConfig;
-use crate::integer::rns::{Integer, Rns};
+use crate::integer::rns::{Common, Integer, Rns};
 use crate::integer::AssignedInteger;
-use crate::maingate::{big_to_fe, AssignedCondition, MainGateConfig, RangeConfig};
-use halo2::halo2curves::{ff::PrimeField, group::Curve};
+use crate::maingate::{big_to_fe, modulus, AssignedCondition, MainGateConfig, RangeConfig};
+use halo2::halo2curves::{
+    ff::PrimeField,
+    group::{Curve, Group},
+};
+use halo2::plonk::Error;
 use num_bigint::BigUint as big_uint;
 use num_traits::One;
 use std::fmt;
@@ -67,6 +74,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         public_data
     }
 
+    /// Returns $x$ coordinate limbs followed by the parity of $y$ as native
+    /// field elements
+    pub fn public_compressed(&self) -> Vec<N> {
+        let mut public_data = self.x.limbs();
+        public_data.push(N::from(self.y.value().bit(0) as u64));
+        public_data
+    }
+
     /// Returns $x$ coordinate
     pub fn x(&self) -> &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         &self.x
@@ -123,6 +138,78 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     pub fn y(&self) -> &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         &self.y
     }
+
+    /// Reconstructs the affine point from the assigned witness values. Returns
+    /// `None` if witness is unknown or coordinates are not on the curve. It
+    /// only reads back assigned values and is meant for debugging under
+    /// `MockProver`.
+    pub fn debug_value<C: CurveAffine<Base = W>>(&self) -> Option<C> {
+        let mut point = None;
+        self.x.integer().zip(self.y.integer()).map(|(x, y)| {
+            let x = big_to_fe::<W>(x.value() % modulus::<W>());
+            let y = big_to_fe::<W>(y.value() % modulus::<W>());
+            point = Option::from(C::from_xy(x, y));
+        });
+        point
+    }
+
+    /// Returns true if both points are placed in the same cells. It doesn't
+    /// depend on witness values so it is safe to alter the layout with it.
+    pub(crate) fn shares_cells(&self, other: &Self) -> bool {
+        fn shares_cells<
+            W: PrimeField,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >(
+            a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ) -> bool {
+            a.native().cell() == b.native().cell()
+                && a.limbs()
+                    .iter()
+                    .zip(b.limbs().iter())
+                    .all(|(a, b)| a.as_ref().cell() == b.as_ref().cell())
+        }
+        shares_cells(&self.x, &other.x) && shares_cells(&self.y, &other.y)
+    }
+}
+
+/// Point that is either on curve or the point at infinity. When `is_identity`
+/// is set coordinates are still of a valid point on curve though its value is
+/// not meaningful.
+#[derive(Clone, Debug)]
+pub struct AssignedPointOrIdentity<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub(crate) point: AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub(crate) is_identity: AssignedCondition<N>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedPointOrIdentity<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedPointOrIdentity` given coordinates and the flag
+    /// Does not check for validity
+    pub fn new(
+        point: AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        is_identity: AssignedCondition<N>,
+    ) -> Self {
+        AssignedPointOrIdentity { point, is_identity }
+    }
+
+    /// Returns the underlying point
+    pub fn point(&self) -> &AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.point
+    }
+
+    /// Returns the condition that is set if the point is the identity
+    pub fn is_identity(&self) -> &AssignedCondition<N> {
+        &self.is_identity
+    }
 }
 
 /// Config for Ecc Chip
@@ -148,16 +235,158 @@ impl EccConfig {
     }
 }
 
+/// Selects the scalar multiplication algorithm that `mul_with_strategy` lays
+/// out. Every strategy computes the same point but they require different
+/// auxiliary points to be assigned for the window size. Strategies map to
+/// algorithms as measured in `test_general_ecc_mul_strategy`:
+///
+/// - `MinRows` is `mul_naf`, fewest rows for window sizes above two
+/// - `MinConstraints` is `mul_booth`, fewest point operations in the table
+/// - `Balanced` is `mul`, same layout as before strategies were added
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MulStrategy {
+    /// Signed odd digits with `mul_naf`. Table holds only odd multiples and
+    /// fewer selections are made per window, so it lays out fewer rows than
+    /// the other strategies for window sizes above two. Requires
+    /// `assign_aux_naf`.
+    MinRows,
+    /// Booth recoding with `mul_booth`. Table is built with one doubling and
+    /// `2^(window_size - 1) - 2` additions, the fewest point operations of the
+    /// three tables, while windows cost more rows than with `MinRows`.
+    /// Requires `assign_aux_booth`.
+    MinConstraints,
+    /// Unsigned windows with `mul`. Requires `assign_aux`.
+    #[default]
+    Balanced,
+}
+
+/// Rows that `mul_with_profile` lays out for each phase of scalar
+/// multiplication. Rows are accumulated so that a profile can be shared
+/// across many multiplications.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MulProfile {
+    /// Bit decomposition and padding of the scalar
+    pub decompose: usize,
+    /// Incremental table on top of the auxiliary point
+    pub make_incremental_table: usize,
+    /// Table selections, one per window
+    pub select_multi: usize,
+    /// Doublings of the accumulator between windows
+    pub double_n: usize,
+    /// Ladder steps of the windows after the second one
+    pub ladder: usize,
+    /// Addition of the second window and the auxiliary correction
+    pub add: usize,
+}
+
+impl MulProfile {
+    /// Returns rows of all phases
+    pub fn total(&self) -> usize {
+        self.decompose
+            + self.make_incremental_table
+            + self.select_multi
+            + self.double_n
+            + self.ladder
+            + self.add
+    }
+}
+
+/// Maximum window size that multiplication algorithms accept. Tables of
+/// `2^window_size` points are built so a larger window is rejected with
+/// `Error::Synthesis` before any table point is assigned.
+pub const MAX_WINDOW_SIZE: usize = 10;
+
+/// Number of candidates that `map_to_curve` tries. About half of the field
+/// elements are abscissas of points on the curve, so mapping fails with
+/// probability about `2^-MAP_TO_CURVE_ATTEMPTS`.
+pub const MAP_TO_CURVE_ATTEMPTS: usize = 32;
+
+/// Error that batch multiplication returns when the region goes past the
+/// offset set with `GeneralEccChip::set_max_rows`. `Error` has no variant
+/// that carries a message so it is wrapped in `Error::Transcript`, use
+/// `RowLimitExceeded::from_error` to recover it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowLimitExceeded {
+    /// Name of the operation that went past the limit
+    pub operation: &'static str,
+    /// Number of rows assigned past the limit
+    pub overflow: usize,
+}
+
+impl RowLimitExceeded {
+    /// Returns the row limit error that `error` wraps if there is one
+    pub fn from_error(error: &Error) -> Option<Self> {
+        match error {
+            Error::Transcript(error) => error.get_ref()?.downcast_ref::<Self>().copied(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RowLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} exceeds the row limit by {} rows",
+            self.operation, self.overflow
+        )
+    }
+}
+
+impl std::error::Error for RowLimitExceeded {}
+
+impl From<RowLimitExceeded> for Error {
+    fn from(error: RowLimitExceeded) -> Self {
+        Error::Transcript(std::io::Error::new(std::io::ErrorKind::Other, error))
+    }
+}
+
+/// Returns `Error::Synthesis` if the window size is zero or exceeds
+/// [`MAX_WINDOW_SIZE`]
+fn check_window_size(window_size: usize) -> Result<(), Error> {
+    if window_size == 0 || window_size > MAX_WINDOW_SIZE {
+        return Err(Error::Synthesis);
+    }
+    Ok(())
+}
+
 /// Finds a point we need to subtract from the end result in the efficient batch
 /// multiplication algorithm.
 ///
 /// Computes AuxFin from AuxInit for batch multiplication
 /// see https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
 fn make_mul_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_pairs: usize) -> C {
+    make_mul_aux_bounded(
+        aux_to_add,
+        window_size,
+        number_of_pairs,
+        C::Scalar::NUM_BITS as usize,
+    )
+}
+
+/// Same as `make_mul_aux` where scalars are decomposed into `number_of_bits`
+/// bits rather than the bit length of the scalar field
+fn make_mul_aux_bounded<C: CurveAffine>(
+    aux_to_add: C,
+    window_size: usize,
+    number_of_pairs: usize,
+    number_of_bits: usize,
+) -> C {
+    let k = mul_aux_multiplier(window_size, number_of_pairs, number_of_bits);
+    (-aux_to_add * big_to_fe::<C::Scalar>(k)).to_affine()
+}
+
+/// Returns `k` such that the auxiliary generator is accumulated `k` times in
+/// the efficient batch multiplication algorithm
+fn mul_aux_multiplier(
+    window_size: usize,
+    number_of_pairs: usize,
+    number_of_bits: usize,
+) -> big_uint {
     assert!(window_size > 0);
     assert!(number_of_pairs > 0);
 
-    let n = C::Scalar::NUM_BITS as usize;
+    let n = number_of_bits;
     let mut number_of_selectors = n / window_size;
     if n % window_size != 0 {
         number_of_selectors += 1;
@@ -169,7 +398,61 @@ fn make_mul_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_pai
     }
     let k1 = (one << number_of_pairs) - 1usize;
     // k = k0* 2^n_pairs
-    let k = k0 * k1;
+    k0 * k1
+}
+
+/// Returns `true` if `aux_to_sub` cancels what the batch multiplication
+/// algorithm accumulates from `aux_to_add`, that is `aux_to_add * k +
+/// aux_to_sub = 0`, and is not the identity so that it can be assigned
+fn mul_aux_cancels<C: CurveAffine>(
+    aux_to_add: C,
+    aux_to_sub: C,
+    window_size: usize,
+    number_of_pairs: usize,
+) -> bool {
+    let k = mul_aux_multiplier(window_size, number_of_pairs, C::Scalar::NUM_BITS as usize);
+    let sum = aux_to_add * big_to_fe::<C::Scalar>(k) + aux_to_sub;
+    bool::from(sum.is_identity()) && !bool::from(aux_to_sub.is_identity())
+}
+
+/// Domain prefix used to derive the auxiliary generator from a tag
+const AUX_DOMAIN_PREFIX: &str = "halo2wrong-ecc-aux";
+
+/// Derives the auxiliary generator deterministically from the tag with
+/// `hash_to_curve` of the curve, so that anyone can reproduce it and check
+/// that it is not chosen relative to the inputs of the circuit
+pub fn aux_generator_from_tag<C: CurveAffine>(tag: &[u8]) -> C {
+    C::CurveExt::hash_to_curve(AUX_DOMAIN_PREFIX)(tag).to_affine()
+}
+
+/// Finds a point we need to subtract from the end result in the signed digit
+/// multiplication algorithm.
+///
+/// Unlike `make_mul_aux` the auxiliary generator is added only once in the
+/// most significant window so it is carried through all doublings.
+fn make_mul_aux_naf<C: CurveAffine>(aux_to_add: C, window_size: usize) -> C {
+    assert!(window_size > 0);
+
+    let n = C::Scalar::NUM_BITS as usize;
+    let mut number_of_windows = n / window_size;
+    if n % window_size != 0 {
+        number_of_windows += 1;
+    }
+    let k = big_uint::one() << ((number_of_windows - 1) * window_size);
+    (-aux_to_add * big_to_fe::<C::Scalar>(k)).to_affine()
+}
+
+/// Finds a point we need to subtract from the end result in the Booth recoded
+/// multiplication algorithm.
+///
+/// Auxiliary generator is added only once as in `make_mul_aux_naf`. Recoding
+/// always uses an additional window to absorb the carry of the most
+/// significant digit.
+fn make_mul_aux_booth<C: CurveAffine>(aux_to_add: C, window_size: usize) -> C {
+    assert!(window_size > 0);
+
+    let number_of_windows = C::Scalar::NUM_BITS as usize / window_size + 1;
+    let k = big_uint::one() << ((number_of_windows - 1) * window_size);
     (-aux_to_add * big_to_fe::<C::Scalar>(k)).to_affine()
 }
 
@@ -177,9 +460,22 @@ fn make_mul_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_pai
 /// scalar.
 ///
 /// Allows to select values of precomputed table in efficient multiplication
-/// algorithm
+/// algorithm. Bits are in little endian order, so that the selector with bits
+/// $b_0, b_1, ...$ selects the table entry at index $\sum_i b_i 2^i$.
 #[derive(Default)]
-pub(crate) struct Selector<F: PrimeField>(Vec<AssignedCondition<F>>);
+pub struct Selector<F: PrimeField>(pub(crate) Vec<AssignedCondition<F>>);
+
+impl<F: PrimeField> Selector<F> {
+    /// Returns a new `Selector` given its bits in little endian order
+    pub fn new(bits: Vec<AssignedCondition<F>>) -> Self {
+        Selector(bits)
+    }
+
+    /// Returns bits of the selector in little endian order
+    pub fn bits(&self) -> &[AssignedCondition<F>] {
+        &self.0
+    }
+}
 
 impl<F: PrimeField> fmt::Debug for Selector<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -192,9 +488,89 @@ impl<F: PrimeField> fmt::Debug for Selector<F> {
     }
 }
 
+/// `Selector` of the absolute value of a signed digit together with its
+/// sign.
+///
+/// Selects the table entry indexed by `bits` as `Selector` does and the
+/// selected value is negated if `sign` is set. It is the window type of
+/// signed recodings such as Booth where only the non negative multiples are
+/// kept in the table.
+pub struct SignedSelector<F: PrimeField> {
+    pub(crate) bits: Selector<F>,
+    pub(crate) sign: AssignedCondition<F>,
+}
+
+impl<F: PrimeField> SignedSelector<F> {
+    /// Returns a new `SignedSelector` given the selector of the absolute value
+    /// and the condition that is set for a negative digit
+    pub fn new(bits: Selector<F>, sign: AssignedCondition<F>) -> Self {
+        SignedSelector { bits, sign }
+    }
+
+    /// Returns selector of the absolute value
+    pub fn bits(&self) -> &Selector<F> {
+        &self.bits
+    }
+
+    /// Returns the condition that is set for a negative digit
+    pub fn sign(&self) -> &AssignedCondition<F> {
+        &self.sign
+    }
+}
+
+impl<F: PrimeField> fmt::Debug for SignedSelector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignedSelector")
+            .field("bits", &self.bits)
+            .field("sign", &self.sign)
+            .finish()
+    }
+}
+
 /// Vector of `Selectors` which represent the binary representation of a scalar
-/// split in window sized selectors.
-pub(crate) struct Windowed<F: PrimeField>(Vec<Selector<F>>);
+/// split in window sized selectors. Selectors are ordered from the most
+/// significant window to the least significant one as the multiplication
+/// ladder consumes them.
+pub struct Windowed<F: PrimeField>(pub(crate) Vec<Selector<F>>);
+
+impl<F: PrimeField> Windowed<F> {
+    /// Returns a new `Windowed` given selectors starting from the most
+    /// significant window
+    pub fn new(selectors: Vec<Selector<F>>) -> Self {
+        Windowed(selectors)
+    }
+
+    /// Returns selectors starting from the most significant window
+    pub fn selectors(&self) -> &[Selector<F>] {
+        &self.0
+    }
+}
+
+/// Splits bits of a scalar into windows of `window_size` bits.
+///
+/// Bits are expected in big endian order and the number of bits must be a
+/// multiple of `window_size`. Decomposition of a scalar comes in little
+/// endian order, so it should be padded with zeros at the end to the next
+/// multiple of `window_size` and then reversed before calling this function,
+/// as `pad` of the chips does. Each window is reversed back into a little
+/// endian `Selector` so that it indexes the table of `make_incremental_table`
+/// where the entry at index $k$ is $A + kP$ for auxiliary point $A$.
+pub fn windows_from_bits<F: PrimeField>(
+    bits: Vec<AssignedCondition<F>>,
+    window_size: usize,
+) -> Windowed<F> {
+    assert!(window_size > 0);
+    assert_eq!(bits.len() % window_size, 0);
+    Windowed(
+        bits.chunks(window_size)
+            .map(|chunk| {
+                let mut selector = chunk.to_vec();
+                selector.reverse();
+                Selector(selector)
+            })
+            .collect(),
+    )
+}
 
 impl<F: PrimeField> fmt::Debug for Windowed<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -210,13 +586,25 @@ impl<F: PrimeField> fmt::Debug for Windowed<F> {
 }
 
 /// Table of precomputed values for efficient multiplication algorithm.
-pub(crate) struct Table<
+pub struct Table<
     W: PrimeField,
     N: PrimeField,
     const NUMBER_OF_LIMBS: usize,
     const BIT_LEN_LIMB: usize,
 >(pub(crate) Vec<AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>);
 
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Table<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Reconstructs the affine points of table entries in order with
+    /// `AssignedPoint::debug_value`. An entry is `None` if its witness is
+    /// unknown or not on the curve. It is meant for debugging under
+    /// `MockProver`.
+    pub fn values<C: CurveAffine<Base = W>>(&self) -> Vec<Option<C>> {
+        self.0.iter().map(|entry| entry.debug_value()).collect()
+    }
+}
+
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     fmt::Debug for Table<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
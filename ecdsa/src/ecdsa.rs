@@ -6,10 +6,10 @@ use ecc::maingate::RegionCtx;
 use ecc::{AssignedPoint, EccConfig, GeneralEccChip};
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::PrimeField;
-use halo2::{circuit::Value, plonk::Error};
+use halo2::plonk::Error;
 use integer::rns::Integer;
 use integer::{AssignedInteger, IntegerInstructions};
-use maingate::{MainGateConfig, RangeConfig};
+use maingate::{AssignedCondition, MainGateConfig, MainGateInstructions, RangeConfig};
 
 #[derive(Clone, Debug)]
 pub struct EcdsaConfig {
@@ -92,6 +92,9 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
 impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     EcdsaChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
+    /// Constraints the signature to be valid for the message hash and the
+    /// public key. Multiplication is laid out with window size `4` so
+    /// auxiliary point must be assigned for window size `4` and 2 pairs.
     pub fn verify(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -101,7 +104,6 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
     ) -> Result<(), Error> {
         let ecc_chip = self.ecc_chip();
         let scalar_chip = ecc_chip.scalar_field_chip();
-        let base_chip = ecc_chip.base_field_chip();
 
         // 1. check 0 < r, s < n
 
@@ -120,20 +122,69 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
         let u2 = scalar_chip.mul(ctx, &sig.r, &s_inv)?;
 
         // 5. compute Q = u1*G + u2*pk
-        let e_gen = ecc_chip.assign_point(ctx, Value::known(E::generator()))?;
-        let pairs = vec![(e_gen, u1), (pk.point.clone(), u2)];
-        let q = ecc_chip.mul_batch_1d_horizontal(ctx, pairs, 4)?;
+        let q = self.mul_generator_and_public_key(ctx, u1, u2, pk, 4)?;
 
-        // 6. reduce q_x in E::ScalarExt
-        // assuming E::Base/E::ScalarExt have the same number of limbs
-        let q_x = q.x();
-        let q_x_reduced_in_q = base_chip.reduce(ctx, q_x)?;
-        let q_x_reduced_in_r = scalar_chip.reduce_external(ctx, &q_x_reduced_in_q)?;
+        // 6. check if Q.x == r (mod n)
+        ecc_chip.assert_x_equals(ctx, &q, &sig.r)
+    }
+
+    /// Same as `verify` except the result is returned as an
+    /// [`AssignedCondition`] that is set only for a valid signature rather
+    /// than being asserted. Zero `r` or `s` and a mismatching `x` coordinate
+    /// unset the condition. Note that `r` and `s` must still be in the scalar
+    /// field and that an exceptional point in the multiplication, such as
+    /// `u1*G + u2*pk` being the identity, makes the circuit unsatisfiable.
+    ///
+    /// Auxiliary point must be assigned for `window_size` and 2 pairs.
+    pub fn verify_ecdsa(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        sig: &AssignedEcdsaSig<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        msg_hash: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+
+        // 1. flag zero r and s, `invert` sets its condition for zero
+        scalar_chip.assert_in_field(ctx, &sig.r)?;
+        scalar_chip.assert_in_field(ctx, &sig.s)?;
+        let (_, r_is_zero) = scalar_chip.invert(ctx, &sig.r)?;
+        let (s_inv, s_is_zero) = scalar_chip.invert(ctx, &sig.s)?;
+
+        // 2. u1 = m' * w (mod n) and u2 = r * w (mod n)
+        let u1 = scalar_chip.mul(ctx, msg_hash, &s_inv)?;
+        let u2 = scalar_chip.mul(ctx, &sig.r, &s_inv)?;
+
+        // 3. compute Q = u1*G + u2*pk
+        let q = self.mul_generator_and_public_key(ctx, u1, u2, pk, window_size)?;
 
-        // 7. check if Q.x == r (mod n)
-        scalar_chip.assert_strict_equal(ctx, &q_x_reduced_in_r, &sig.r)?;
+        // 4. flag Q.x == r (mod n) as the difference being zero
+        let q_x = ecc_chip.reduce_to_scalar(ctx, q.x())?;
+        let diff = scalar_chip.sub(ctx, &q_x, &sig.r)?;
+        let (_, is_equal) = scalar_chip.invert(ctx, &diff)?;
 
-        Ok(())
+        let r_is_not_zero = main_gate.not(ctx, &r_is_zero)?;
+        let s_is_not_zero = main_gate.not(ctx, &s_is_zero)?;
+        main_gate.and_many(ctx, &[r_is_not_zero, s_is_not_zero, is_equal])
+    }
+
+    /// Computes `u1*G + u2*pk` in a batch where the generator is assigned as
+    /// a constant
+    fn mul_generator_and_public_key(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        u1: AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        u2: AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let generator = ecc_chip.assign_constant(ctx, E::generator())?;
+        let pairs = vec![(generator, u1), (pk.point.clone(), u2)];
+        ecc_chip.mul_batch_1d_horizontal(ctx, pairs, window_size)
     }
 }
 
@@ -157,6 +208,7 @@ mod tests {
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
     use integer::IntegerInstructions;
     use maingate::mock_prover_verify;
+    use maingate::MainGateInstructions;
     use maingate::{MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions};
     use rand_core::OsRng;
     use std::marker::PhantomData;
@@ -356,4 +408,163 @@ mod tests {
         run::<Secp256k1, PastaFp>();
         run::<Secp256k1, PastaFq>();
     }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitEcdsaVerifyFlag<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E>,
+        signature: Value<(E::Scalar, E::Scalar)>,
+        msg_hash: Value<E::Scalar>,
+        valid: bool,
+
+        aux_generator: E,
+        window_size: usize,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: PrimeField> Circuit<N> for TestCircuitEcdsaVerifyFlag<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                valid: self.valid,
+                ..Self::default()
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 2)?;
+                    Ok(())
+                },
+            )?;
+
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = self.signature.map(|signature| signature.0);
+                    let s = self.signature.map(|signature| signature.1);
+                    let integer_r = ecc_chip.new_unassigned_scalar(r);
+                    let integer_s = ecc_chip.new_unassigned_scalar(s);
+                    let msg_hash = ecc_chip.new_unassigned_scalar(self.msg_hash);
+
+                    let sig = AssignedEcdsaSig {
+                        r: scalar_chip.assign_integer(ctx, integer_r, Range::Remainder)?,
+                        s: scalar_chip.assign_integer(ctx, integer_s, Range::Remainder)?,
+                    };
+                    let pk_assigned = AssignedPublicKey {
+                        point: ecc_chip.assign_point(ctx, self.public_key)?,
+                    };
+                    let msg_hash = scalar_chip.assign_integer(ctx, msg_hash, Range::Remainder)?;
+
+                    let valid = ecdsa_chip.verify_ecdsa(
+                        ctx,
+                        &sig,
+                        &pk_assigned,
+                        &msg_hash,
+                        self.window_size,
+                    )?;
+                    if self.valid {
+                        main_gate.assert_one(ctx, &valid)
+                    } else {
+                        main_gate.assert_zero(ctx, &valid)
+                    }
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_verify_flag() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::{Fp, Fq, Secp256k1Affine as Secp256k1};
+        use num_bigint::BigUint;
+
+        fn from_hex<F: PrimeField>(hex: &str) -> F {
+            big_to_fe(BigUint::parse_bytes(hex.as_bytes(), 16).unwrap())
+        }
+
+        // RFC 6979 deterministic signatures of sha256("Satoshi Nakamoto") with
+        // private keys `1` and `n - 1`
+        let msg_hash: Fq =
+            from_hex("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e");
+        let vectors = [
+            (
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+                "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0",
+                "6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5",
+            ),
+        ];
+
+        let mut cases = vec![];
+        for (x, y, r, s) in vectors {
+            let public_key = Secp256k1::from_xy(from_hex::<Fp>(x), from_hex::<Fp>(y)).unwrap();
+            cases.push((public_key, from_hex(r), from_hex(s), msg_hash, true));
+        }
+        let (public_key, r, s, msg_hash, _) = cases[0];
+        cases.extend([
+            // tampered message, signature and public key
+            (public_key, r, s, msg_hash + Fq::ONE, false),
+            (public_key, r + Fq::ONE, s, msg_hash, false),
+            (public_key, r, s + Fq::ONE, msg_hash, false),
+            (-public_key, r, s, msg_hash, false),
+            // zero `s`
+            (public_key, r, Fq::ZERO, msg_hash, false),
+            // negated `s` is the well known malleated signature
+            (public_key, r, -s, msg_hash, true),
+        ]);
+
+        for (i, (public_key, r, s, msg_hash, valid)) in cases.into_iter().enumerate() {
+            let aux_generator = <Secp256k1 as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let circuit = TestCircuitEcdsaVerifyFlag::<Secp256k1, BnScalar> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r, s)),
+                msg_hash: Value::known(msg_hash),
+                valid,
+                aux_generator,
+                window_size: 2 + i % 3,
+                ..Default::default()
+            };
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+    }
 }
@@ -1,22 +1,106 @@
 use halo2::{
     arithmetic::Field,
     circuit::{AssignedCell, Cell, Region, Value},
-    plonk::{Advice, Column, Error, Fixed, Selector},
+    plonk::{Advice, Column, Error, Fixed, Instance, Selector},
 };
 
 pub mod utils;
 pub use halo2;
 pub use halo2::halo2curves as curves;
+use std::any::TypeId;
 
+/// Wraps a `Region` along with the running offset that assignments are made
+/// at. Chips take the context rather than an offset so that the offset moves
+/// forward with every assigned row without being threaded manually.
 #[derive(Debug)]
 pub struct RegionCtx<'a, F: Field> {
     region: Region<'a, F>,
     offset: usize,
+    constants: Option<Vec<(F, AssignedCell<F, F>)>>,
+    decompositions: Option<Vec<(TypeId, Vec<Cell>, Vec<AssignedCell<F, F>>)>>,
 }
 
 impl<'a, F: Field> RegionCtx<'a, F> {
     pub fn new(region: Region<'a, F>, offset: usize) -> RegionCtx<'a, F> {
-        RegionCtx { region, offset }
+        RegionCtx {
+            region,
+            offset,
+            constants: None,
+            decompositions: None,
+        }
+    }
+
+    /// Same as `new` but constants assigned in this region are cached so that
+    /// assigning an already assigned constant again reuses its cell. The cache
+    /// lives in the context hence it never outlives the region.
+    pub fn new_with_constant_cache(region: Region<'a, F>, offset: usize) -> RegionCtx<'a, F> {
+        RegionCtx {
+            region,
+            offset,
+            constants: Some(vec![]),
+            decompositions: None,
+        }
+    }
+
+    /// Same as `new` but bit decompositions made in this region are cached so
+    /// that decomposing the same cells again reuses the bits. Entries are
+    /// keyed on the identity of the decomposed cells and on the type that
+    /// the decomposition is made for, so cells decomposed under another
+    /// modulus are decomposed again.
+    pub fn new_with_decomposition_cache(region: Region<'a, F>, offset: usize) -> RegionCtx<'a, F> {
+        RegionCtx {
+            region,
+            offset,
+            constants: None,
+            decompositions: Some(vec![]),
+        }
+    }
+
+    /// Returns the cell a constant is already assigned to in this region if
+    /// constant caching is enabled
+    pub fn cached_constant(&self, constant: F) -> Option<AssignedCell<F, F>> {
+        self.constants.as_ref().and_then(|constants| {
+            constants
+                .iter()
+                .find(|(value, _)| *value == constant)
+                .map(|(_, cell)| cell.clone())
+        })
+    }
+
+    /// Records the cell a constant is assigned to if constant caching is
+    /// enabled
+    pub fn cache_constant(&mut self, constant: F, cell: &AssignedCell<F, F>) {
+        if let Some(constants) = self.constants.as_mut() {
+            constants.push((constant, cell.clone()));
+        }
+    }
+
+    /// Returns bits that the cells are already decomposed into for `kind` in
+    /// this region if decomposition caching is enabled
+    pub fn cached_decomposition(
+        &self,
+        kind: TypeId,
+        cells: &[Cell],
+    ) -> Option<Vec<AssignedCell<F, F>>> {
+        self.decompositions.as_ref().and_then(|decompositions| {
+            decompositions
+                .iter()
+                .find(|(key_kind, key_cells, _)| *key_kind == kind && key_cells == cells)
+                .map(|(_, _, bits)| bits.clone())
+        })
+    }
+
+    /// Records the bits that the cells are decomposed into for `kind` if
+    /// decomposition caching is enabled
+    pub fn cache_decomposition(
+        &mut self,
+        kind: TypeId,
+        cells: &[Cell],
+        bits: &[AssignedCell<F, F>],
+    ) {
+        if let Some(decompositions) = self.decompositions.as_mut() {
+            decompositions.push((kind, cells.to_vec(), bits.to_vec()));
+        }
     }
 
     pub fn offset(&self) -> usize {
@@ -55,6 +139,21 @@ impl<'a, F: Field> RegionCtx<'a, F> {
             .assign_advice(annotation, column, self.offset, || value)
     }
 
+    pub fn assign_advice_from_instance<A, AR>(
+        &mut self,
+        annotation: A,
+        instance: Column<Instance>,
+        row: usize,
+        column: Column<Advice>,
+    ) -> Result<AssignedCell<F, F>, Error>
+    where
+        A: Fn() -> AR,
+        AR: Into<String>,
+    {
+        self.region
+            .assign_advice_from_instance(annotation, instance, row, column, self.offset)
+    }
+
     pub fn constrain_equal(&mut self, cell_0: Cell, cell_1: Cell) -> Result<(), Error> {
         self.region.constrain_equal(cell_0, cell_1)
     }
@@ -1,13 +1,16 @@
+use std::any::TypeId;
 use std::rc::Rc;
 
 use super::{AssignedInteger, AssignedLimb, UnassignedInteger};
 use crate::instructions::{IntegerInstructions, Range};
 use crate::rns::{Common, Integer, Rns};
+use halo2::circuit::{Cell, Value};
 use halo2::halo2curves::ff::PrimeField;
 use halo2::plonk::Error;
-use maingate::{halo2, AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx};
+use maingate::{halo2, AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx, Term};
 use maingate::{MainGate, MainGateConfig};
-use maingate::{RangeChip, RangeConfig};
+use maingate::{RangeChip, RangeConfig, RangeInstructions};
+use num_bigint::BigUint as big_uint;
 
 mod add;
 mod assert_in_field;
@@ -16,8 +19,11 @@ mod assert_zero;
 mod assign;
 mod div;
 mod invert;
+#[cfg(feature = "dynamic-lookup")]
+mod lookup;
 mod mul;
 mod reduce;
+mod sqrt;
 mod square;
 
 /// Configuration for [`IntegerChip`]
@@ -65,6 +71,35 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         BIT_LEN_LIMB / number_of_lookup_limbs
     }
 
+    /// Bit length of the limb at `idx` of an integer in the wrong field. Only
+    /// the most significant limbs can be shorter than `BIT_LEN_LIMB` and
+    /// limbs above the wrong modulus are empty.
+    fn limb_bit_len(&self, idx: usize) -> usize {
+        let wrong_modulus_bit_len = self.rns.wrong_modulus.bits() as usize;
+        BIT_LEN_LIMB.min(wrong_modulus_bit_len.saturating_sub(idx * BIT_LEN_LIMB))
+    }
+
+    /// Returns `Error::Synthesis` if the integer is laid out with an [`Rns`]
+    /// that is not the one of the chip. Number of limbs and bit length of
+    /// limbs are fixed by the type, yet an integer can still carry an `Rns`
+    /// with another modulus or limb bounds.
+    fn check_rns(
+        &self,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let rns = &integer.rns;
+        let matches = Rc::ptr_eq(rns, &self.rns)
+            || (rns.wrong_modulus == self.rns.wrong_modulus
+                && rns.bit_len_lookup == self.rns.bit_len_lookup
+                && rns.max_reduced_limb == self.rns.max_reduced_limb
+                && rns.max_unreduced_limb == self.rns.max_unreduced_limb);
+        if matches {
+            Ok(())
+        } else {
+            Err(Error::Synthesis)
+        }
+    }
+
     /// Creates a new [`AssignedInteger`] from its limb representation and its
     /// native value
     pub(crate) fn new_assigned_integer(
@@ -99,6 +134,24 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.assign_integer_generic(ctx, integer, range)
     }
 
+    fn assign_from_bytes(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: Value<&[u8]>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let bit_len = self.rns.wrong_modulus.bits();
+        let number_of_bytes = ((bit_len + 7) / 8) as usize;
+        bytes.error_if_known_and(|bytes| bytes.len() != number_of_bytes)?;
+
+        let integer = bytes.map(big_uint::from_bytes_be);
+        integer.error_if_known_and(|integer| integer.bits() > bit_len)?;
+
+        let integer = integer.map(|integer| Integer::from_big(integer, Rc::clone(&self.rns)));
+        let assigned = self.assign_integer(ctx, integer.into(), Range::Remainder)?;
+        self.assert_in_field(ctx, &assigned)?;
+        Ok(assigned)
+    }
+
     fn assign_constant(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -112,32 +165,124 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedCondition<N>>, Error> {
+        let kind = TypeId::of::<W>();
+        let cells: Vec<Cell> = integer
+            .limbs()
+            .iter()
+            .map(|limb| limb.as_ref().cell())
+            .collect();
+        if let Some(decomposed) = ctx.cached_decomposition(kind, &cells) {
+            return Ok(decomposed);
+        }
+
         self.assert_in_field(ctx, integer)?;
 
         let main_gate = self.main_gate();
 
         let mut decomposed = Vec::new();
         for idx in 0..NUMBER_OF_LIMBS {
-            let number_of_bits = if idx == NUMBER_OF_LIMBS - 1 {
-                self.rns.wrong_modulus.bits() as usize % BIT_LEN_LIMB
-            } else {
-                BIT_LEN_LIMB
-            };
+            let number_of_bits = self.limb_bit_len(idx);
+            if number_of_bits == 0 {
+                // limb lies above the wrong modulus
+                main_gate.assert_zero(ctx, integer.limb(idx))?;
+                continue;
+            }
             let decomposed_limb = main_gate.to_bits(ctx, integer.limb(idx), number_of_bits)?;
             decomposed.extend(decomposed_limb);
         }
 
         assert_eq!(decomposed.len(), self.rns.wrong_modulus.bits() as usize);
+        ctx.cache_decomposition(kind, &cells, &decomposed);
+
+        Ok(decomposed)
+    }
+
+    fn decompose_strict(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedCondition<N>>, Error> {
+        let decomposed = self.decompose(ctx, integer)?;
+
+        let main_gate = self.main_gate();
+        let mut base = N::ONE;
+        let terms: Vec<Term<N>> = decomposed
+            .iter()
+            .map(|bit| {
+                let term = Term::Assigned(bit, base);
+                base = base.double();
+                term
+            })
+            .collect();
+        let composed = main_gate.compose(ctx, &terms, N::ZERO)?;
+        main_gate.assert_equal(ctx, &composed, integer.native())?;
+
+        Ok(decomposed)
+    }
+
+    fn decompose_with_lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        self.assert_in_field(ctx, integer)?;
+
+        let main_gate = self.main_gate();
+        let range_chip = self.range_chip();
+
+        let mut decomposed = Vec::new();
+        for idx in 0..NUMBER_OF_LIMBS {
+            // most significant limb takes the overflow length that is
+            // configured for remainders
+            let number_of_bits = self.limb_bit_len(idx);
+            let limb = integer.limb(idx);
+            if number_of_bits == 0 {
+                // limb lies above the wrong modulus
+                main_gate.assert_zero(ctx, limb)?;
+                continue;
+            }
+            let (composed, sublimbs) = range_chip.decompose(
+                ctx,
+                limb.value().copied(),
+                Self::sublimb_bit_len(),
+                number_of_bits,
+            )?;
+            main_gate.assert_equal(ctx, &composed, limb)?;
+            decomposed.extend(sublimbs);
+        }
 
         Ok(decomposed)
     }
 
+    fn is_odd(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let decomposed = self.decompose(ctx, integer)?;
+        Ok(decomposed[0].clone())
+    }
+
+    fn is_greater_than_half(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        // `2a` is less than `p` iff `a <= (p - 1) / 2` otherwise `2a - p` is
+        // odd since modulus is odd
+        let doubled = self.mul2(ctx, integer)?;
+        let doubled = self.reduce(ctx, &doubled)?;
+        self.is_odd(ctx, &doubled)
+    }
+
     fn add(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
+        self.check_rns(b)?;
         let (a, b) = (
             &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
             &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
@@ -192,6 +337,8 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
+        self.check_rns(b)?;
         let (a, b) = (
             &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
             &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
@@ -223,12 +370,24 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.neg_generic(ctx, a)
     }
 
+    fn neg_if(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?;
+        self.neg_if_generic(ctx, a, cond)
+    }
+
     fn mul(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
+        self.check_rns(b)?;
         let (a, b) = (
             &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
             &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
@@ -240,6 +399,38 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.mul_generic(ctx, a, b)
     }
 
+    fn mul_add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
+        self.check_rns(b)?;
+        self.check_rns(c)?;
+        let (a, b) = (
+            &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
+            &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
+        );
+        let (a, b) = (
+            &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
+            &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
+        );
+        // Limbs of the addend are accumulated into intermediate values so
+        // they must be at most a reduced limb as it is assumed for residues
+        let c = &self.reduce_if_limb_values_exceeds_reduced(ctx, c)?;
+        // Quotient must be found in its range. It always is for a remainder
+        // addend and operands in the operand range.
+        let max_sum = (&self.rns.max_mul_quotient + 1usize) * &self.rns.wrong_modulus;
+        let c = &if a.max_val() * b.max_val() + c.max_val() >= max_sum {
+            self.reduce(ctx, c)?
+        } else {
+            c.clone()
+        };
+        self.mul_add_generic(ctx, a, b, Some(c))
+    }
+
     fn mul_constant(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -278,6 +469,26 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.square_generic(ctx, a)
     }
 
+    fn pow_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exp: u64,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if exp == 0 {
+            return self.assign_constant(ctx, W::ONE);
+        }
+        // most significant bit is consumed by the initial value
+        let mut acc = a.clone();
+        for i in (0..(63 - exp.leading_zeros())).rev() {
+            acc = self.square(ctx, &acc)?;
+            if (exp >> i) & 1 == 1 {
+                acc = self.mul(ctx, &acc, a)?;
+            }
+        }
+        Ok(acc)
+    }
+
     fn div(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -344,14 +555,40 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.invert_incomplete_generic(ctx, a)
     }
 
+    fn sqrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.sqrt_generic(ctx, a)
+    }
+
     fn reduce(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
         self.reduce_generic(ctx, a)
     }
 
+    fn conditional_reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.check_rns(a)?;
+        self.reduce_if_limb_values_exceeds_unreduced(ctx, a)
+    }
+
     fn assert_equal(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -376,6 +613,31 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Ok(())
     }
 
+    fn assert_equal_canonical(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        #[cfg(debug_assertions)]
+        for integer in [a, b] {
+            debug_assert!(
+                integer
+                    .limbs
+                    .iter()
+                    .all(|limb| limb.max_val() <= self.rns.max_reduced_limb),
+                "assert_equal_canonical: input is not reduced"
+            );
+            integer.integer().map(|integer| {
+                debug_assert!(
+                    integer.value() < self.rns.wrong_modulus,
+                    "assert_equal_canonical: input is not less than the modulus"
+                )
+            });
+        }
+        self.assert_strict_equal(ctx, a, b)
+    }
+
     fn assert_not_equal(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -411,6 +673,25 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Ok(())
     }
 
+    fn constrain_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.assert_zero_generic(ctx, a)
+    }
+
+    fn constrain_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let minus_one = Integer::from_fe(-W::ONE, Rc::clone(&self.rns));
+        let a = &self.add_constant(ctx, a, &minus_one)?;
+        self.constrain_zero(ctx, a)
+    }
+
     fn assert_strict_zero(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -447,6 +728,30 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         main_gate.assert_bit(ctx, a.limb(0))
     }
 
+    fn from_condition(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let zero = self.main_gate().assign_constant(ctx, N::ZERO)?;
+        let limbs: Vec<AssignedLimb<N>> = (0..NUMBER_OF_LIMBS)
+            .map(|i| match i {
+                0 => AssignedLimb::from(cond.clone(), big_uint::from(1usize)),
+                _ => AssignedLimb::from(zero.clone(), big_uint::from(0usize)),
+            })
+            .collect();
+        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), cond.clone()))
+    }
+
+    fn to_condition(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        self.assert_strict_bit(ctx, a)?;
+        Ok(a.limb(0).clone())
+    }
+
     fn select(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -509,6 +814,32 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.assert_in_field_generic(ctx, a)
     }
 
+    fn assert_reduced(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        #[cfg(debug_assertions)]
+        {
+            debug_assert!(
+                a.limbs
+                    .iter()
+                    .all(|limb| limb.max_val() <= self.rns.max_reduced_limb),
+                "assert_reduced: input is not reduced"
+            );
+            a.integer().map(|integer| {
+                debug_assert!(
+                    integer.value() < self.rns.wrong_modulus,
+                    "assert_reduced: input is not less than the modulus"
+                )
+            });
+        }
+        // Range check limbs of a copy and bind it to the input
+        let reduced = &self.assign_integer_generic(ctx, a.integer().into(), Range::Remainder)?;
+        self.assert_strict_equal(ctx, a, reduced)?;
+        self.assert_in_field_generic(ctx, reduced)
+    }
+
     fn sign(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -554,13 +885,13 @@ mod tests {
     use crate::{PrimeField, UnassignedInteger};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
-    use maingate::mock_prover_verify;
     use maingate::{
         big_to_fe, decompose_big, fe_to_big, halo2, AssignedCondition, MainGate, MainGateConfig,
-        MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx, Term,
     };
+    use maingate::{mock_prover_verify, mock_prover_verify_fails};
     use num_bigint::{BigUint as big_uint, RandBigInt};
-    use num_traits::Zero;
+    use num_traits::{One, Zero};
     use rand_core::OsRng;
     use std::rc::Rc;
 
@@ -1100,6 +1431,11 @@ mod tests {
                     integer_chip.assert_equal(ctx, &must_be_self, a)?;
                     main_gate.assert_one(ctx, &cond)?;
 
+                    // 0 / 0
+                    let (c_1, cond) = integer_chip.div(ctx, &zero, &zero)?;
+                    integer_chip.assert_zero(ctx, &c_1)?;
+                    main_gate.assert_one(ctx, &cond)?;
+
                     // a / b
                     let c_1 = integer_chip.div_incomplete(ctx, a, b)?;
                     integer_chip.assert_equal(ctx, c_0, &c_1)?;
@@ -1480,7 +1816,7 @@ mod tests {
     );
 
     impl_circuit!(
-        TestCircuitSign,
+        TestCircuitDecompositionStrict,
         fn synthesize(
             &self,
             config: Self::Config,
@@ -1494,19 +1830,105 @@ mod tests {
                 |region| {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
-
-                    let integer = t.new_from_big(big_uint::from(20u64));
+                    let integer = t.rand_in_field();
+                    let integer_big = integer.value();
                     let assigned =
                         integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
-                    let assigned_sign = integer_chip.sign(ctx, &assigned)?;
-                    main_gate.assert_zero(ctx, &assigned_sign)?;
+                    let decomposed = integer_chip.decompose_strict(ctx, &assigned)?;
+                    let expected =
+                        decompose_big::<W>(integer_big, self.rns.wrong_modulus.bits() as usize, 1);
+                    for (c, expected) in decomposed.iter().zip(expected.into_iter()) {
+                        if expected != W::ZERO {
+                            main_gate.assert_one(ctx, c)?;
+                        } else {
+                            main_gate.assert_zero(ctx, c)?;
+                        }
+                    }
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
 
-                    let integer = t.new_from_big(big_uint::from(21u64));
+    impl_circuit!(
+        TestCircuitDecompositionWithLookup,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let sublimb_bit_len =
+                        IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::sublimb_bit_len();
+
+                    let integers = vec![
+                        t.rand_in_field(),
+                        t.new_from_big(big_uint::zero()),
+                        t.new_from_big(&self.rns.wrong_modulus - 1usize),
+                    ];
+                    for integer in integers {
+                        let assigned =
+                            integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+
+                        let offset = ctx.offset();
+                        let bits = integer_chip.decompose(ctx, &assigned)?;
+                        let bits_rows = ctx.offset() - offset;
+
+                        let offset = ctx.offset();
+                        let sublimbs = integer_chip.decompose_with_lookup(ctx, &assigned)?;
+                        let lookup_rows = ctx.offset() - offset;
+                        assert!(lookup_rows < bits_rows);
+
+                        // sublimbs are the chunks of the bit decomposition
+                        let chunks = bits.chunks(sublimb_bit_len);
+                        assert_eq!(chunks.len(), sublimbs.len());
+                        for (chunk, sublimb) in chunks.zip(sublimbs.iter()) {
+                            let terms: Vec<Term<N>> = chunk
+                                .iter()
+                                .enumerate()
+                                .map(|(i, bit)| {
+                                    Term::Assigned(bit, big_to_fe(big_uint::one() << i))
+                                })
+                                .collect();
+                            let composed = main_gate.compose(ctx, &terms, N::ZERO)?;
+                            main_gate.assert_equal(ctx, &composed, sublimb)?;
+                        }
+                    }
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitDecompositionOverRange,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    // limbs fit in the remainder range but the value is the
+                    // wrong modulus
+                    let integer = t.new_from_limbs(&self.rns.wrong_modulus_decomposed);
                     let assigned =
                         integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
-                    let assigned_sign = integer_chip.sign(ctx, &assigned)?;
-                    main_gate.assert_one(ctx, &assigned_sign)?;
-
+                    integer_chip.decompose_strict(ctx, &assigned)?;
                     Ok(())
                 },
             )?;
@@ -1514,38 +1936,1050 @@ mod tests {
         }
     );
 
-    macro_rules! test_circuit_runner {
-        (
-            $circuit:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
-        ) => {
-            $(
-                let (rns, _):(Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+    impl_circuit!(
+        TestCircuitAssignFromBytes,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
 
-                let circuit = $circuit::<$wrong_field, $native_field, $bit_len_limb> { rns: Rc::new(rns) };
-            let instance = vec![vec![]];
-            mock_prover_verify(&circuit, instance);
-            )*
-        };
-    }
+                    let p = &self.rns.wrong_modulus;
+                    let number_of_bytes = ((p.bits() + 7) / 8) as usize;
+                    let to_bytes = |value: &big_uint| {
+                        let bytes = value.to_bytes_be();
+                        let mut padded = vec![0u8; number_of_bytes - bytes.len()];
+                        padded.extend(bytes);
+                        padded
+                    };
+
+                    let values = vec![
+                        big_uint::zero(),
+                        big_uint::one(),
+                        p - 1usize,
+                        t.rand_in_field().value(),
+                    ];
+                    for value in values {
+                        let bytes = to_bytes(&value);
+                        let assigned =
+                            integer_chip.assign_from_bytes(ctx, Value::known(&bytes[..]))?;
+                        let expected = t.new_from_big(value);
+                        assigned.integer().map(|integer| {
+                            assert_eq!(integer.limbs(), expected.limbs());
+                        });
+                    }
 
-    macro_rules! test_circuit {
-        (
-            $circuit:ident
-        ) => {
-            use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
-            use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
-            use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
-            test_circuit_runner!(
-                $circuit,
-                [PastaFp, PastaFq, 68],
-                [PastaFq, PastaFp, 68],
-                [BnBase, BnScalar, 68],
-                [BnScalar, BnScalar, 68],
-                [Secp256k1Base, BnScalar, 68],
-                [Secp256k1Base, PastaFp, 68],
-                [Secp256k1Base, PastaFq, 68],
-                [Secp256k1Scalar, BnScalar, 68],
-                [Secp256k1Scalar, PastaFp, 68],
+                    let bytes = to_bytes(&big_uint::one());
+                    // shorter and longer than the modulus
+                    for bytes in [&bytes[1..], &[&[0u8][..], &bytes[..]].concat()[..]] {
+                        assert!(matches!(
+                            integer_chip.assign_from_bytes(ctx, Value::known(bytes)),
+                            Err(Error::Synthesis)
+                        ));
+                    }
+                    // top byte exceeds the modulus bit length
+                    if p.bits() % 8 != 0 {
+                        let bytes = to_bytes(&(big_uint::one() << (p.bits() as usize)));
+                        assert!(matches!(
+                            integer_chip.assign_from_bytes(ctx, Value::known(&bytes[..])),
+                            Err(Error::Synthesis)
+                        ));
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitAssignFromBytesNotInField,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let bytes = self.rns.wrong_modulus.to_bytes_be();
+                    integer_chip.assign_from_bytes(ctx, Value::known(&bytes[..]))?;
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitRnsMismatch,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            use crate::AssignedInteger;
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+
+                    // same limbs carried with the rns of another modulus
+                    let mut other = (*self.rns).clone();
+                    other.wrong_modulus += 2usize;
+                    let b = AssignedInteger::new(Rc::new(other), a.limbs(), a.native().clone());
+
+                    assert!(matches!(
+                        integer_chip.add(ctx, &a, &b),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        integer_chip.sub(ctx, &b, &a),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        integer_chip.mul(ctx, &a, &b),
+                        Err(Error::Synthesis)
+                    ));
+                    assert!(matches!(
+                        integer_chip.reduce(ctx, &b),
+                        Err(Error::Synthesis)
+                    ));
+
+                    // an equal rns in another allocation is accepted
+                    let c = AssignedInteger::new(
+                        Rc::new((*self.rns).clone()),
+                        a.limbs(),
+                        a.native().clone(),
+                    );
+                    let sum = integer_chip.add(ctx, &a, &c)?;
+                    let doubled = integer_chip.mul2(ctx, &a)?;
+                    integer_chip.assert_equal(ctx, &sum, &doubled)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitIsOdd,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = &self.rns.wrong_modulus;
+                    let half = (p - 1usize) >> 1;
+                    let mut values: Vec<big_uint> =
+                        (0..4).map(|_| t.rand_in_field().value()).collect();
+                    values.extend([
+                        big_uint::zero(),
+                        big_uint::one(),
+                        half.clone(),
+                        &half + 1usize,
+                        p - 1usize,
+                    ]);
+
+                    for value in values {
+                        let integer = t.new_from_big(value.clone());
+                        let assigned =
+                            integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+
+                        let is_odd = integer_chip.is_odd(ctx, &assigned)?;
+                        if value.bit(0) {
+                            main_gate.assert_one(ctx, &is_odd)?;
+                        } else {
+                            main_gate.assert_zero(ctx, &is_odd)?;
+                        }
+
+                        let is_greater = integer_chip.is_greater_than_half(ctx, &assigned)?;
+                        if value > half {
+                            main_gate.assert_one(ctx, &is_greater)?;
+                        } else {
+                            main_gate.assert_zero(ctx, &is_greater)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitMulAdd,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for (c, range) in [
+                        (t.rand_in_field(), Range::Remainder),
+                        (t.max_in_remainder_range(), Range::Remainder),
+                        (t.rand_in_unreduced_range(), Range::Unreduced),
+                    ] {
+                        let a = t.rand_in_operand_range();
+                        let b = t.rand_in_operand_range();
+                        let d = (a.value() * b.value() + c.value()) % &self.rns.wrong_modulus;
+                        let d = t.new_from_big(d);
+
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                        let b = &integer_chip.assign_integer(ctx, b.into(), Range::Operand)?;
+                        let is_reduced = matches!(range, Range::Remainder);
+                        let c = &integer_chip.assign_integer(ctx, c.into(), range)?;
+                        let d_0 = &integer_chip.assign_integer(ctx, d.into(), Range::Remainder)?;
+
+                        let offset = ctx.offset();
+                        let d_1 = &integer_chip.mul_add(ctx, a, b, c)?;
+                        let fused_rows = ctx.offset() - offset;
+                        assert_eq!(d_1.max_val(), self.rns.max_remainder);
+
+                        let offset = ctx.offset();
+                        let ab = &integer_chip.mul(ctx, a, b)?;
+                        let mul_rows = ctx.offset() - offset;
+                        let d_2 = &integer_chip.add(ctx, ab, c)?;
+                        let separate_rows = ctx.offset() - offset;
+
+                        integer_chip.assert_equal(ctx, d_0, d_1)?;
+                        integer_chip.assert_equal(ctx, d_1, d_2)?;
+                        integer_chip.assert_strict_equal(ctx, d_0, d_1)?;
+                        // unreduced addend is reduced first
+                        if is_reduced {
+                            assert_eq!(fused_rows, mul_rows);
+                            assert!(fused_rows < separate_rows);
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitPowSmall,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for exp in 0..=5u64 {
+                        let a = t.rand_in_field();
+                        let c = a
+                            .value()
+                            .modpow(&big_uint::from(exp), &self.rns.wrong_modulus);
+                        let c = t.new_from_big(c);
+
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                        let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+
+                        let offset = ctx.offset();
+                        let c_1 = &integer_chip.pow_small(ctx, a, exp)?;
+                        if exp == 1 {
+                            assert_eq!(ctx.offset(), offset);
+                        }
+                        integer_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitExposeLimbs,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            let assigned = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let a = t.max_in_remainder_range();
+                    integer_chip.assign_integer(ctx, a.into(), Range::Remainder)
+                },
+            )?;
+
+            for (row, limb) in assigned.limb_values().into_iter().enumerate() {
+                main_gate.expose_public(layouter.namespace(|| "limb"), limb, row)?;
+            }
+            main_gate.expose_public(
+                layouter.namespace(|| "native"),
+                assigned.native().clone(),
+                NUMBER_OF_LIMBS,
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSign,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let integer = t.new_from_big(big_uint::from(20u64));
+                    let assigned =
+                        integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+                    let assigned_sign = integer_chip.sign(ctx, &assigned)?;
+                    main_gate.assert_zero(ctx, &assigned_sign)?;
+
+                    let integer = t.new_from_big(big_uint::from(21u64));
+                    let assigned =
+                        integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+                    let assigned_sign = integer_chip.sign(ctx, &assigned)?;
+                    main_gate.assert_one(ctx, &assigned_sign)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSqrt,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = W::random(OsRng);
+                    let residue = r.square();
+                    let non_residue = residue * W::MULTIPLICATIVE_GENERATOR;
+
+                    for a in [residue, non_residue, W::ZERO] {
+                        let is_square: bool = a.sqrt().is_some().into();
+                        let a = Integer::from_fe(a, Rc::clone(&self.rns));
+                        let a = &integer_chip.assign_integer(
+                            ctx,
+                            Value::known(a).into(),
+                            Range::Remainder,
+                        )?;
+                        let (root, cond) = integer_chip.sqrt(ctx, a)?;
+                        if is_square {
+                            main_gate.assert_one(ctx, &cond)?;
+                            let root_square = &integer_chip.square(ctx, &root)?;
+                            integer_chip.assert_equal(ctx, root_square, a)?;
+                        } else {
+                            main_gate.assert_zero(ctx, &cond)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitWrongInverse,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    // random witness instead of the inverse
+                    let inv = t.rand_in_field();
+
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(a).into(),
+                        Range::Remainder,
+                    )?;
+                    let inv = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(inv).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.mul_into_one(ctx, a, inv)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitEqualityCanonical,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    let a_0 = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(a.clone()).into(),
+                        Range::Remainder,
+                    )?;
+                    let a_1 = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(a).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.assert_in_field(ctx, a_0)?;
+                    integer_chip.assert_in_field(ctx, a_1)?;
+
+                    let offset = ctx.offset();
+                    integer_chip.assert_equal(ctx, a_0, a_1)?;
+                    let general_rows = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    integer_chip.assert_equal_canonical(ctx, a_0, a_1)?;
+                    let canonical_rows = ctx.offset() - offset;
+                    assert!(canonical_rows < general_rows);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitEqualityCanonicalUnreduced,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    let b = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    // limbs of the sum are not reduced
+                    let c_0 = &integer_chip.add(ctx, a, b)?;
+                    let c_1 = &integer_chip.add(ctx, b, a)?;
+                    integer_chip.assert_equal_canonical(ctx, c_0, c_1)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitConstrainZeroOne,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // canonical zero and one
+                    let zero = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.zero()).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.constrain_zero(ctx, zero)?;
+                    let one = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.new_from_big(big_uint::one())).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.constrain_one(ctx, one)?;
+
+                    // wrong modulus is a non canonical zero
+                    let wrong_modulus = t.new_from_big(self.rns.wrong_modulus.clone());
+                    let zero = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(wrong_modulus).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.constrain_zero(ctx, zero)?;
+                    let one = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.new_from_big(&self.rns.wrong_modulus + big_uint::one()))
+                            .into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.constrain_one(ctx, one)?;
+
+                    // non reduced results of arithmetic
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_remainder_range()).into(),
+                        Range::Remainder,
+                    )?;
+                    let zero = &integer_chip.sub(ctx, a, a)?;
+                    integer_chip.constrain_zero(ctx, zero)?;
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    let (a_inv, _) = &integer_chip.invert(ctx, a)?;
+                    let one = &integer_chip.mul(ctx, a, a_inv)?;
+                    integer_chip.constrain_one(ctx, one)?;
+
+                    let offset = ctx.offset();
+                    integer_chip.constrain_zero(ctx, zero)?;
+                    let constrain_rows = ctx.offset() - offset;
+                    let offset = ctx.offset();
+                    let zero_constant = integer_chip.assign_constant(ctx, W::ZERO)?;
+                    integer_chip.assert_equal(ctx, zero, &zero_constant)?;
+                    let equal_rows = ctx.offset() - offset;
+                    assert!(constrain_rows < equal_rows);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitConstrainZeroNonZero,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let one = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(Integer::from_fe(W::ONE, Rc::clone(&self.rns))).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.constrain_zero(ctx, one)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitAssertReduced,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    let b = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    integer_chip.assert_reduced(ctx, a)?;
+                    let c = &integer_chip.mul(ctx, a, b)?;
+                    integer_chip.assert_reduced(ctx, c)?;
+                    let c = &integer_chip.reduce(ctx, &integer_chip.add(ctx, a, b)?)?;
+                    integer_chip.assert_reduced(ctx, c)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitAssertReducedUnreduced,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    let b = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.rand_in_field()).into(),
+                        Range::Remainder,
+                    )?;
+                    // limbs of the sum are not reduced
+                    let c = &integer_chip.add(ctx, a, b)?;
+                    integer_chip.assert_reduced(ctx, c)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitConditionalReduce,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let number_of_additions = 8;
+                    let a = t.rand_in_field();
+                    let b = t.rand_in_field();
+                    let c = t.rand_in_field();
+                    let expected = ((a.value() + b.value() * number_of_additions) * c.value())
+                        % &self.rns.wrong_modulus;
+                    let expected = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(t.new_from_big(expected)).into(),
+                        Range::Remainder,
+                    )?;
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    let c = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+
+                    // additions are interleaved with reductions and followed by
+                    // a multiplication
+                    let mut results = vec![];
+                    let mut rows = vec![];
+                    for eager in [true, false] {
+                        let offset = ctx.offset();
+                        let mut acc = a.clone();
+                        for _ in 0..number_of_additions {
+                            acc = integer_chip.add(ctx, &acc, b)?;
+                            acc = if eager {
+                                integer_chip.reduce(ctx, &acc)?
+                            } else {
+                                integer_chip.conditional_reduce(ctx, &acc)?
+                            };
+                        }
+                        results.push(integer_chip.mul(ctx, &acc, c)?);
+                        rows.push(ctx.offset() - offset);
+                    }
+                    for result in results.iter() {
+                        integer_chip.assert_equal(ctx, result, expected)?;
+                    }
+                    assert!(rows[1] < rows[0]);
+
+                    // no row while limbs are within the bound and a reduction
+                    // once they exceed it
+                    let offset = ctx.offset();
+                    let a = &integer_chip.conditional_reduce(ctx, a)?;
+                    assert_eq!(ctx.offset(), offset);
+                    let d = t.rand_in_unreduced_range();
+                    let d = &integer_chip.assign_integer(ctx, d.into(), Range::Unreduced)?;
+                    let d = &integer_chip.add(ctx, d, d)?;
+                    let offset = ctx.offset();
+                    let e = &integer_chip.conditional_reduce(ctx, d)?;
+                    assert!(ctx.offset() > offset);
+                    assert_eq!(e.max_val(), self.rns.max_remainder);
+                    integer_chip.assert_equal(ctx, d, e)?;
+                    integer_chip.add(ctx, a, e)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    // `a` and `a + p` where `a` is small enough that both fit in the
+    // remainder range
+    fn non_canonical_pair<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+        t: &TestRNS<W, N, BIT_LEN_LIMB>,
+    ) -> (
+        Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) {
+        use rand_core::RngCore;
+        let a = big_uint::from(OsRng.next_u32());
+        let b = &a + &t.rns.wrong_modulus;
+        assert!(b <= t.rns.max_remainder);
+        (t.new_from_big(a), t.new_from_big(b))
+    }
+
+    impl_circuit!(
+        TestCircuitStrictEquality,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let (a, b) = non_canonical_pair(&t);
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+
+                    // residues are equal
+                    integer_chip.assert_equal(ctx, a, b)?;
+                    // limbs are equal once `b` is reduced
+                    let b = &integer_chip.reduce(ctx, b)?;
+                    integer_chip.assert_strict_equal(ctx, a, b)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitStrictEqualityNonCanonical,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let (a, b) = non_canonical_pair(&t);
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    integer_chip.assert_strict_equal(ctx, a, b)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitDecompositionCache,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let main_gate = integer_chip.main_gate();
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new_with_decomposition_cache(region, offset);
+
+                    let a = t.rand_in_field();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+
+                    let offset = ctx.offset();
+                    let bits_0 = integer_chip.decompose(ctx, a)?;
+                    assert!(ctx.offset() > offset);
+
+                    // second decomposition of the same cells is free
+                    let offset = ctx.offset();
+                    let bits_1 = integer_chip.decompose(ctx, &a.clone())?;
+                    assert_eq!(ctx.offset(), offset);
+                    for (bit_0, bit_1) in bits_0.iter().zip(bits_1.iter()) {
+                        assert_eq!(bit_0.cell(), bit_1.cell());
+                    }
+
+                    // same value in other cells is decomposed again
+                    let b =
+                        &integer_chip.assign_integer(ctx, a.integer().into(), Range::Remainder)?;
+                    let offset = ctx.offset();
+                    let bits_2 = integer_chip.decompose(ctx, b)?;
+                    assert!(ctx.offset() > offset);
+                    for (bit_0, bit_2) in bits_0.iter().zip(bits_2.iter()) {
+                        main_gate.assert_equal(ctx, bit_0, bit_2)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 1",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // without the cache every decomposition takes rows
+                    let a = t.rand_in_field();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    integer_chip.decompose(ctx, a)?;
+                    let offset = ctx.offset();
+                    integer_chip.decompose(ctx, a)?;
+                    assert!(ctx.offset() > offset);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitConditionConversion,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let main_gate = integer_chip.main_gate();
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    for bit in [0u64, 1] {
+                        let integer = Integer::from_fe(W::from(bit), Rc::clone(&self.rns));
+                        let integer =
+                            &integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+                        let cond = &main_gate.assign_bit(ctx, Value::known(N::from(bit)))?;
+
+                        // condition to integer and back
+                        let from_cond = &integer_chip.from_condition(ctx, cond)?;
+                        integer_chip.assert_strict_equal(ctx, from_cond, integer)?;
+                        let back = &integer_chip.to_condition(ctx, from_cond)?;
+                        main_gate.assert_equal(ctx, back, cond)?;
+
+                        // integer to condition and back
+                        let to_cond = &integer_chip.to_condition(ctx, integer)?;
+                        main_gate.assert_equal(ctx, to_cond, cond)?;
+                        let back = &integer_chip.from_condition(ctx, to_cond)?;
+                        integer_chip.assert_strict_equal(ctx, back, integer)?;
+
+                        // converted condition feeds integer arithmetic
+                        let expected = (a.value() + bit) % &self.rns.wrong_modulus;
+                        let expected = &integer_chip.assign_integer(
+                            ctx,
+                            Value::known(t.new_from_big(expected)).into(),
+                            Range::Remainder,
+                        )?;
+                        let a = &integer_chip.assign_integer(
+                            ctx,
+                            Value::known(a.clone()).into(),
+                            Range::Remainder,
+                        )?;
+                        let sum = &integer_chip.add(ctx, a, from_cond)?;
+                        integer_chip.assert_equal(ctx, sum, expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitToConditionNotBit,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let two = Integer::from_fe(W::from(2), Rc::clone(&self.rns));
+                    let two = &integer_chip.assign_integer(ctx, two.into(), Range::Remainder)?;
+                    integer_chip.to_condition(ctx, two)?;
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    macro_rules! test_circuit_runner {
+        (
+            $circuit:ident, $verify:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
+        ) => {
+            $(
+                let (rns, _):(Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+
+                let circuit = $circuit::<$wrong_field, $native_field, $bit_len_limb> { rns: Rc::new(rns) };
+            let instance = vec![vec![]];
+            $verify(&circuit, instance);
+            )*
+        };
+    }
+
+    macro_rules! test_circuit {
+        (
+            $circuit:ident
+        ) => {
+            test_circuit!($circuit, mock_prover_verify)
+        };
+        (
+            $circuit:ident, $verify:ident
+        ) => {
+            use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+            use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+            use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
+            test_circuit_runner!(
+                $circuit,
+                $verify,
+                [PastaFp, PastaFq, 68],
+                [PastaFq, PastaFp, 68],
+                [BnBase, BnScalar, 68],
+                [BnScalar, BnScalar, 68],
+                [Secp256k1Base, BnScalar, 68],
+                [Secp256k1Base, PastaFp, 68],
+                [Secp256k1Base, PastaFq, 68],
+                [Secp256k1Scalar, BnScalar, 68],
+                [Secp256k1Scalar, PastaFp, 68],
                 [Secp256k1Scalar, PastaFq, 68]
             );
         };
@@ -1576,6 +3010,14 @@ mod tests {
         test_circuit!(TestCircuitNonDeterministic);
     }
     #[test]
+    fn test_integer_circuit_sqrt() {
+        test_circuit!(TestCircuitSqrt);
+    }
+    #[test]
+    fn test_integer_circuit_wrong_inverse() {
+        test_circuit!(TestCircuitWrongInverse, mock_prover_verify_fails);
+    }
+    #[test]
     fn test_integer_circuit_equality() {
         test_circuit!(TestCircuitEquality);
     }
@@ -1592,7 +3034,238 @@ mod tests {
         test_circuit!(TestCircuitDecomposition);
     }
     #[test]
+    fn test_integer_circuit_decomposition_strict() {
+        test_circuit!(TestCircuitDecompositionStrict);
+    }
+    #[test]
+    fn test_integer_circuit_decomposition_with_lookup() {
+        test_circuit!(TestCircuitDecompositionWithLookup);
+    }
+    #[test]
+    fn test_integer_circuit_decomposition_over_range() {
+        test_circuit!(TestCircuitDecompositionOverRange, mock_prover_verify_fails);
+    }
+    #[test]
     fn test_integer_circuit_sign() {
         test_circuit!(TestCircuitSign);
     }
+    #[test]
+    fn test_integer_circuit_assign_from_bytes() {
+        test_circuit!(TestCircuitAssignFromBytes);
+    }
+    #[test]
+    fn test_integer_circuit_assign_from_bytes_not_in_field() {
+        test_circuit!(
+            TestCircuitAssignFromBytesNotInField,
+            mock_prover_verify_fails
+        );
+    }
+    #[test]
+    fn test_integer_circuit_rns_mismatch() {
+        test_circuit!(TestCircuitRnsMismatch);
+    }
+    #[test]
+    fn test_integer_circuit_is_odd() {
+        test_circuit!(TestCircuitIsOdd);
+    }
+    #[test]
+    fn test_integer_circuit_mul_add() {
+        test_circuit!(TestCircuitMulAdd);
+    }
+    #[test]
+    fn test_integer_circuit_pow_small() {
+        test_circuit!(TestCircuitPowSmall);
+    }
+    #[test]
+    fn test_integer_circuit_equality_canonical() {
+        test_circuit!(TestCircuitEqualityCanonical);
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "assert_equal_canonical: input is not reduced")]
+    fn test_integer_circuit_equality_canonical_unreduced() {
+        test_circuit!(TestCircuitEqualityCanonicalUnreduced);
+    }
+    #[test]
+    fn test_integer_circuit_constrain_zero_one() {
+        test_circuit!(TestCircuitConstrainZeroOne);
+    }
+    #[test]
+    fn test_integer_circuit_constrain_zero_non_zero() {
+        test_circuit!(TestCircuitConstrainZeroNonZero, mock_prover_verify_fails);
+    }
+    #[test]
+    fn test_integer_circuit_assert_reduced() {
+        test_circuit!(TestCircuitAssertReduced);
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "assert_reduced: input is not reduced")]
+    fn test_integer_circuit_assert_reduced_unreduced() {
+        test_circuit!(TestCircuitAssertReducedUnreduced);
+    }
+    #[test]
+    fn test_integer_circuit_conditional_reduce() {
+        test_circuit!(TestCircuitConditionalReduce);
+    }
+    #[test]
+    fn test_integer_circuit_strict_equality() {
+        test_circuit!(TestCircuitStrictEquality);
+    }
+    #[test]
+    fn test_integer_circuit_strict_equality_non_canonical() {
+        test_circuit!(
+            TestCircuitStrictEqualityNonCanonical,
+            mock_prover_verify_fails
+        );
+    }
+    #[test]
+    fn test_integer_circuit_decomposition_cache() {
+        test_circuit!(TestCircuitDecompositionCache);
+    }
+    #[test]
+    fn test_integer_circuit_condition_conversion() {
+        test_circuit!(TestCircuitConditionConversion);
+    }
+    #[test]
+    fn test_integer_circuit_to_condition_not_bit() {
+        test_circuit!(TestCircuitToConditionNotBit, mock_prover_verify_fails);
+    }
+    #[test]
+    fn test_integer_circuit_expose_limbs() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+
+        macro_rules! run {
+            ($([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*) => {
+                $(
+                    let (rns, _): (Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+                    let rns = Rc::new(rns);
+                    let expected = Integer::from_big(rns.max_remainder.clone(), Rc::clone(&rns));
+                    let mut public_inputs = expected.limbs();
+                    public_inputs.push(expected.native());
+
+                    let circuit = TestCircuitExposeLimbs::<$wrong_field, $native_field, $bit_len_limb> { rns };
+                    mock_prover_verify(&circuit, vec![public_inputs.clone()]);
+
+                    // exposed cells are bound to the integer
+                    public_inputs[0] += $native_field::from(1u64);
+                    mock_prover_verify_fails(&circuit, vec![public_inputs]);
+                )*
+            };
+        }
+
+        run!(
+            [PastaFp, PastaFq, 68],
+            [BnBase, BnScalar, 68],
+            [Secp256k1Base, PastaFp, 68]
+        );
+    }
+
+    struct TestCircuitLimbRows<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        rows: std::cell::Cell<usize>,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+        Circuit<N> for TestCircuitLimbRows<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let overflow_bit_lens =
+                Rns::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct().overflow_lengths();
+            let composition_bit_len =
+                IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::sublimb_bit_len();
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                vec![composition_bit_len],
+                overflow_bit_lens,
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let a = UnassignedInteger(Value::unknown());
+                    let b = UnassignedInteger(Value::unknown());
+                    let a = integer_chip.assign_integer(ctx, a, Range::Remainder)?;
+                    let b = integer_chip.assign_integer(ctx, b, Range::Remainder)?;
+
+                    let offset = ctx.offset();
+                    let c = integer_chip.mul(ctx, &a, &b)?;
+                    integer_chip.decompose(ctx, &c)?;
+                    self.rows.set(ctx.offset() - offset);
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    fn limb_rows<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    >() -> usize {
+        let circuit = TestCircuitLimbRows::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            rns: Rc::new(Rns::construct()),
+            rows: std::cell::Cell::new(0),
+        };
+        maingate::DimensionMeasurement::measure(&circuit).unwrap();
+        circuit.rows.get()
+    }
+
+    #[test]
+    fn test_integer_three_limbs() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+        use halo2::halo2curves::ff::Field;
+
+        // three limbs must be wide enough to span the native modulus
+        let rns = Rc::new(Rns::<Secp256k1Base, BnScalar, 3, 88>::construct());
+        for _ in 0..100 {
+            let a = Secp256k1Base::random(OsRng);
+            let b = Secp256k1Base::random(OsRng);
+            let a_int = Integer::from_fe(a, Rc::clone(&rns));
+            let b_int = Integer::from_fe(b, Rc::clone(&rns));
+            let c = a_int.mul(&b_int).result;
+            assert_eq!(c.value(), fe_to_big(a * b));
+        }
+
+        let rows_three_limbs = limb_rows::<Secp256k1Base, BnScalar, 3, 88>();
+        let rows_four_limbs = limb_rows::<Secp256k1Base, BnScalar, 4, 68>();
+        assert!(rows_three_limbs < rows_four_limbs);
+    }
 }
@@ -2,7 +2,10 @@ use crate::chip::IntegerChip;
 use crate::rns::Integer;
 use crate::{AssignedInteger, AssignedLimb, Common, PrimeField};
 use halo2::plonk::Error;
-use maingate::{fe_to_big, halo2, MainGateInstructions, RegionCtx, Term};
+use maingate::{
+    fe_to_big, halo2, AssignedCondition, AssignedValue, CombinationOptionCommon,
+    MainGateInstructions, RegionCtx, Term,
+};
 use num_bigint::BigUint as big_uint;
 use std::rc::Rc;
 
@@ -174,6 +177,53 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Ok(self.new_assigned_integer(&c_limbs, c_native))
     }
 
+    pub(super) fn neg_if_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let aux = a.make_aux();
+
+        // c = a + cond * (aux - 2 * a) so that c = aux - a if cond is set
+        // and c = a otherwise. Single row per limb where `neg` followed by
+        // `select` would take two.
+        let mut neg_if = |a: &AssignedValue<N>, aux: N| -> Result<AssignedValue<N>, Error> {
+            let c = a
+                .value()
+                .zip(cond.value())
+                .map(|(a, cond)| *a + *cond * (aux - a.double()));
+            Ok(main_gate
+                .apply(
+                    ctx,
+                    [
+                        Term::Assigned(cond, aux),
+                        Term::assigned_to_add(a),
+                        Term::unassigned_to_sub(c),
+                    ],
+                    N::ZERO,
+                    CombinationOptionCommon::CombineToNextScaleMul(N::ZERO, -N::from(2)).into(),
+                )?
+                .swap_remove(2))
+        };
+
+        let c_limbs = a
+            .limbs()
+            .iter()
+            .zip(aux.limbs().iter())
+            .map(|(a_limb, aux)| {
+                let c_max = std::cmp::max(a_limb.max_val(), fe_to_big(*aux));
+                let c_limb = neg_if(a_limb.as_ref(), *aux)?;
+                Ok(AssignedLimb::from(c_limb, c_max))
+            })
+            .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?
+            .try_into()
+            .unwrap();
+        let c_native = neg_if(a.native(), aux.native())?;
+        Ok(self.new_assigned_integer(&c_limbs, c_native))
+    }
+
     pub(crate) fn mul2_generic(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -0,0 +1,82 @@
+use super::IntegerChip;
+use crate::{AssignedInteger, AssignedLimb};
+use halo2::halo2curves::ff::PrimeField;
+use halo2::plonk::Error;
+use maingate::{halo2, AssignedValue, MainGateInstructions, RegionCtx, Term};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Number of keys that an integer occupies in the dynamic lookup table
+    pub fn dynamic_table_width() -> usize {
+        NUMBER_OF_LIMBS + 1
+    }
+
+    /// Loads integers to the dynamic lookup table. Limbs and the native value
+    /// of `i`th integer are placed under consecutive keys starting from
+    /// `tag + i * (NUMBER_OF_LIMBS + 1)`. Key ranges of different tables must
+    /// not overlap.
+    pub fn load_dynamic_table(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        tag: N,
+        table: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let width = N::from(Self::dynamic_table_width() as u64);
+
+        for (i, integer) in table.iter().enumerate() {
+            let key = tag + N::from(i as u64) * width;
+            for (j, limb) in integer.limbs().iter().enumerate() {
+                main_gate.add_to_dynamic_table(ctx, key + N::from(j as u64), limb.as_ref())?;
+            }
+            let j = NUMBER_OF_LIMBS as u64;
+            main_gate.add_to_dynamic_table(ctx, key + N::from(j), integer.native())?;
+        }
+        Ok(())
+    }
+
+    /// Looks up the integer at `index` of a table that is loaded with
+    /// `load_dynamic_table` under the same `tag`. Maximum values of the
+    /// result are the maximum values among the table entries.
+    pub fn dynamic_lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        tag: N,
+        index: &AssignedValue<N>,
+        table: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(!table.is_empty());
+        let main_gate = self.main_gate();
+        let width = N::from(Self::dynamic_table_width() as u64);
+
+        // key = index * width + tag
+        let key = &main_gate.compose(ctx, &[Term::Assigned(index, width)], tag)?;
+
+        // An index out of the table falls back to the first entry and fails
+        // at the lookup
+        let selected = index.value().map(|index| {
+            (0..table.len())
+                .position(|i| N::from(i as u64) == *index)
+                .unwrap_or(0)
+        });
+
+        let limbs = (0..NUMBER_OF_LIMBS)
+            .map(|j| {
+                let value = selected.and_then(|i| table[i].limbs[j].value());
+                let value = main_gate.dynamic_lookup(ctx, key, N::from(j as u64), value)?;
+                let max_val = table
+                    .iter()
+                    .map(|integer| integer.limbs[j].max_val())
+                    .max()
+                    .unwrap();
+                Ok(AssignedLimb::from(value, max_val))
+            })
+            .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?;
+
+        let native = selected.and_then(|i| table[i].native().value().copied());
+        let native = main_gate.dynamic_lookup(ctx, key, N::from(NUMBER_OF_LIMBS as u64), native)?;
+
+        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native))
+    }
+}
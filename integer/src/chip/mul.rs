@@ -64,23 +64,44 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Ok(())
     }
 
-    #[allow(clippy::needless_range_loop)]
     pub(super) fn mul_generic(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.mul_add_generic(ctx, a, b, None)
+    }
+
+    /// Constrains `a * b + c = w * quotient + result` where a missing addend
+    /// is a plain multiplication. Limbs of the addend are placed in the
+    /// otherwise unused column of the first row of each intermediate value so
+    /// that the addend costs no extra rows.
+    #[allow(clippy::needless_range_loop)]
+    pub(super) fn mul_add_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: Option<&AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let main_gate = self.main_gate();
         let (zero, one) = (N::ZERO, N::ONE);
 
         let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
 
-        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = a
-            .integer()
-            .zip(b.integer())
-            .map(|(a_int, b_int)| a_int.mul(&b_int))
-            .into();
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = match c {
+            Some(c) => a
+                .integer()
+                .zip(b.integer())
+                .zip(c.integer())
+                .map(|((a_int, b_int), c_int)| a_int.mul_add(&b_int, &c_int)),
+            None => a
+                .integer()
+                .zip(b.integer())
+                .map(|(a_int, b_int)| a_int.mul(&b_int)),
+        }
+        .into();
         let result = witness.result();
         let quotient = witness.long();
 
@@ -128,6 +149,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 }
                 .into();
 
+                // addend limb is added once with the first row
+                let addend = match c {
+                    Some(c) if j == 0 => Some(c.limb(i)),
+                    _ => None,
+                };
+
                 let t_i = main_gate
                     .apply(
                         ctx,
@@ -135,7 +162,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                             Term::Assigned(a.limb(j), zero),
                             Term::Assigned(b.limb(k), zero),
                             Term::Assigned(quotient.limb(k), negative_wrong_modulus[j]),
-                            Term::Zero,
+                            match addend {
+                                Some(addend) => Term::Assigned(addend, one),
+                                None => Term::Zero,
+                            },
                             Term::Unassigned(intermediate_value, -one),
                         ],
                         zero,
@@ -157,6 +187,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                         let p = negative_wrong_modulus[j];
                         t - (*a * *b + *q * p)
                     });
+                if let Some(addend) = addend {
+                    intermediate_value = intermediate_value
+                        .zip(addend.value())
+                        .map(|(t, addend)| t - *addend);
+                }
 
                 // Sanity check for the last running subtraction value
                 {
@@ -183,7 +218,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 Term::Assigned(a.native(), zero),
                 Term::Assigned(b.native(), zero),
                 Term::Assigned(quotient.native(), -self.rns.wrong_modulus_in_native_modulus),
-                Term::Zero,
+                match c {
+                    Some(c) => Term::Assigned(c.native(), one),
+                    None => Term::Zero,
+                },
                 Term::Assigned(result.native(), -one),
             ],
             zero,
@@ -0,0 +1,58 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{big_to_fe, fe_to_big, halo2, AssignedCondition, MainGateInstructions, RegionCtx};
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn sqrt_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let main_gate = self.main_gate();
+
+        // Multiplicative generator of the wrong field is a non residue. So if `a`
+        // has no square root then `a * g` must have one.
+        let g = W::MULTIPLICATIVE_GENERATOR;
+        let g_integer = Integer::from_fe(g, Rc::clone(&self.rns));
+        let one = Integer::from_fe(W::ONE, Rc::clone(&self.rns));
+
+        let a_w = a.integer().map(|a| big_to_fe::<W>(a.value()));
+        let is_square = a_w.map(|a| bool::from(a.sqrt().is_some()));
+        let root = a_w.zip(is_square).map(|(a, is_square)| {
+            let root = if is_square { a.sqrt() } else { (a * g).sqrt() };
+            Integer::from_big(fe_to_big(root.unwrap()), Rc::clone(&self.rns))
+        });
+
+        let cond = main_gate.assign_bit(
+            ctx,
+            is_square.map(|is_square| if is_square { N::ONE } else { N::ZERO }),
+        )?;
+        let root = self.assign_integer(ctx, root.into(), Range::Remainder)?;
+
+        // root^2 = a if cond = 1
+        // root^2 = a * g if cond = 0
+        let root_square = &self.square(ctx, &root)?;
+        let a_mul_g = &self.mul_constant(ctx, a, &g_integer)?;
+        let target = &self.select(ctx, a, a_mul_g, &cond)?;
+        self.assert_equal(ctx, root_square, target)?;
+
+        // Zero is a square, so `a * g = 0` must not be accepted as a proof of
+        // non residuosity. If cond = 0 the root must be non zero.
+        let not_cond = &main_gate.not(ctx, &cond)?;
+        let must_be_non_zero = &self.select_or_assign(ctx, &root, &one, not_cond)?;
+        self.assert_not_zero(ctx, must_be_non_zero)?;
+
+        Ok((root, cond))
+    }
+}
@@ -1,6 +1,7 @@
 use super::{AssignedInteger, UnassignedInteger};
-use crate::maingate::{halo2, AssignedCondition, RegionCtx};
+use crate::maingate::{halo2, AssignedCondition, AssignedValue, RegionCtx};
 use crate::rns::Integer;
+use halo2::circuit::Value;
 use halo2::plonk::Error;
 use maingate::halo2::halo2curves::ff::PrimeField;
 
@@ -35,6 +36,17 @@ pub trait IntegerInstructions<
         range: Range,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Assigns an [`Integer`] given as big-endian bytes with range check for
+    /// [`Range::Remainder`] and constraints it to be less than modulus.
+    /// Returns `Error::Synthesis` if the number of bytes doesn't match the
+    /// byte length of the modulus or the value exceeds the bit length of the
+    /// modulus.
+    fn assign_from_bytes(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: Value<&[u8]>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Assigns an [`Integer`] constant to a cell in the circuit returning an
     /// [`AssignedInteger`].
     fn assign_constant(
@@ -43,13 +55,55 @@ pub trait IntegerInstructions<
         integer: W,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
-    /// Decomposes an [`AssignedInteger`] into its bit representation.
+    /// Decomposes an [`AssignedInteger`] into its bit representation. If the
+    /// region is created with `RegionCtx::new_with_decomposition_cache` an
+    /// integer with the same limb cells is decomposed only once in the
+    /// region and later calls return the same bits without new rows.
     fn decompose(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedCondition<N>>, Error>;
 
+    /// Decomposes an [`AssignedInteger`] into its bit representation as
+    /// `decompose` does and additionally constrains the bits to recompose to
+    /// the native value of the integer. Integers that are not in the field
+    /// make the circuit unsatisfiable.
+    fn decompose_strict(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedCondition<N>>, Error>;
+
+    /// Decomposes an [`AssignedInteger`] in the field into little endian
+    /// sublimbs that are range checked with the lookup table of the range
+    /// chip rather than into bits. Each limb is split into sublimbs of the
+    /// bit length the range table is already configured with, so it takes
+    /// fewer rows than `decompose` where bits are not required. Integers that
+    /// are not in the field make the circuit unsatisfiable.
+    fn decompose_with_lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error>;
+
+    /// Returns the least significant bit of the value of an
+    /// [`AssignedInteger`] in the field. Bit is taken from `decompose` so
+    /// integers that are not in the field make the circuit unsatisfiable.
+    fn is_odd(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Returns `1` if the value of an [`AssignedInteger`] reduced by the
+    /// wrong modulus $p$ is greater than $(p - 1) / 2$, returns `0` otherwise.
+    fn is_greater_than_half(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
     /// Adds 2 [`AssignedInteger`].
     fn add(
         &self,
@@ -113,6 +167,15 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Multiplies an [`AssignedInteger`] by -1 if the [`AssignedCondition`]
+    /// is set.
+    fn neg_if(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Multiplies 2 [`AssignedInteger`].
     fn mul(
         &self,
@@ -121,6 +184,16 @@ pub trait IntegerInstructions<
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Computes `a * b + c` with a single reduction. Costs as many rows as
+    /// `mul` while `mul` followed by `add` also pays for the addition.
+    fn mul_add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Multiplies [`AssignedInteger`] by constant.
     fn mul_constant(
         &self,
@@ -145,9 +218,20 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Raises an [`AssignedInteger`] to a small constant power with square and
+    /// multiply. Sequence of operations only depends on `exp`. Returns the
+    /// constant one if `exp` is zero and `a` itself if `exp` is one.
+    fn pow_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exp: u64,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Divides 2 [`AssignedInteger`]. An [`AssignedCondition`] is returned
-    /// along with the division result indicating if the operation was
-    /// successful.
+    /// along with the division result which is set if the denominator is
+    /// zero, in which case the result is the numerator itself. A zero
+    /// numerator results in zero for any denominator.
     fn div(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -170,8 +254,8 @@ pub trait IntegerInstructions<
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
     /// Inverts an [`AssignedInteger`]. An [`AssignedCondition`] is returned
-    /// along with the inversion result indicating if the operation was
-    /// successful
+    /// along with the inversion result which is set if the input is zero, in
+    /// which case the result is one
     fn invert(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -191,6 +275,22 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Computes square root of an [`AssignedInteger`]. An
+    /// [`AssignedCondition`] is returned along with the root indicating if
+    /// the input is a quadratic residue. If it is not, returned root is the
+    /// square root of the input multiplied by a fixed non residue.
+    fn sqrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    >;
+
     /// Applies reduction to an [`AssignedInteger`]. Reduces the input less than
     /// next power of two of the modulus
     fn reduce(
@@ -199,6 +299,16 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Reduces an [`AssignedInteger`] only if the tracked maximum value of
+    /// any of its limbs exceeds the [`Rns`] `max_unreduced_limb`, otherwise
+    /// returns it as is without new rows. Lazily reduced integers are still
+    /// accepted by additions and reduced where multiplication requires it.
+    fn conditional_reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Constraints that two [`AssignedInteger`] are equal.
     fn assert_equal(
         &self,
@@ -207,7 +317,13 @@ pub trait IntegerInstructions<
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
-    /// Constraints that limbs of two [`AssignedInteger`] are equal.
+    /// Constraints that limbs of two [`AssignedInteger`] are equal. Unlike
+    /// `assert_equal` no reduction is applied, so integers with the same
+    /// residue but different representations such as `a` and `a + p` fail.
+    /// It pins the limb layout an integer is expected to have, for example
+    /// to reject a non-canonical witness against a canonical one. Passing
+    /// doesn't imply that either integer is canonical since both may share
+    /// the same non-canonical limbs, which `assert_in_field` rules out.
     fn assert_strict_equal(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -215,6 +331,20 @@ pub trait IntegerInstructions<
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Constraints that two canonical [`AssignedInteger`]s are equal. Both
+    /// must be less than the modulus, for example asserted with
+    /// `assert_in_field`, so that equal values have equal limbs and limbs
+    /// are compared as in `assert_strict_equal` without the subtraction and
+    /// the reduction of `assert_equal`. In debug builds panics if the tracked
+    /// bounds of a limb exceed the reduced limb bound or if the witness is
+    /// not less than the modulus.
+    fn assert_equal_canonical(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
     /// Constraints that two [`AssignedInteger`] are not equal.
     fn assert_not_equal(
         &self,
@@ -237,6 +367,23 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Constraints that an [`AssignedInteger`] is congruent to zero. Unlike
+    /// `assert_zero` limbs are not required to be zero so any representation
+    /// of zero such as the wrong modulus itself passes.
+    fn constrain_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
+    /// Constraints that an [`AssignedInteger`] is congruent to one. Any
+    /// representation of one passes as in `constrain_zero`.
+    fn constrain_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
     /// Constraints that limbs of an [`AssignedInteger`] is equal to zero
     fn assert_strict_zero(
         &self,
@@ -260,6 +407,25 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Returns an [`AssignedInteger`] equal to `0` or `1` as the
+    /// [`AssignedCondition`] is. The condition is used as the first limb and
+    /// as the native value while other limbs are zero.
+    fn from_condition(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Constraints an [`AssignedInteger`] to be a bit as `assert_strict_bit`
+    /// does and returns its first limb as an [`AssignedCondition`]. Limbs are
+    /// compared as they are, so a bit that is represented with other limbs
+    /// such as an output of `sub` makes the circuit unsatisfiable.
+    fn to_condition(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
     /// Constraints that an [`AssignedInteger`] is less than modulus
     fn assert_in_field(
         &self,
@@ -267,6 +433,18 @@ pub trait IntegerInstructions<
         input: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Constraints that an [`AssignedInteger`] is already reduced, that is
+    /// each limb is in the remainder range and the value is less than the
+    /// modulus. Unlike `assert_in_field` no reduction is applied to the
+    /// input so an unreduced intermediate fails rather than being silently
+    /// reduced. In debug builds panics if the tracked bounds of a limb exceed
+    /// the reduced limb bound or if the witness is not less than the modulus.
+    fn assert_reduced(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
     /// Given an [`AssignedCondition`] returns picks one of two
     /// [`AssignedInteger`]
     fn select(
@@ -8,6 +8,7 @@ use crate::rns::{Common, Integer, Limb};
 use halo2::{circuit::Value, halo2curves::ff::PrimeField};
 use maingate::{big_to_fe, compose, fe_to_big, AssignedValue};
 use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
 use rns::Rns;
 use std::rc::Rc;
 
@@ -168,6 +169,18 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         &self.native_value
     }
 
+    /// Returns assigned limbs as plain [`AssignedValue`]s. These are the cells
+    /// the chip constrains, so copy constraints to them, for example with
+    /// `expose_public`, bind external circuitry to this integer.
+    pub fn limb_values(&self) -> [AssignedValue<N>; NUMBER_OF_LIMBS] {
+        self.limbs
+            .iter()
+            .map(|limb| limb.as_ref().clone())
+            .collect::<Vec<AssignedValue<N>>>()
+            .try_into()
+            .unwrap()
+    }
+
     /// Witness form of the assigned integer that is used to derive further
     /// witnesses
     pub fn integer(&self) -> Value<Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
@@ -175,6 +188,22 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         limbs.map(|limbs| Integer::new(limbs, Rc::clone(&self.rns)))
     }
 
+    /// Returns true if the tracked bounds show that the integer is zero as it
+    /// is for a constant zero. It doesn't depend on witness values so it is
+    /// safe to alter the layout with it.
+    pub fn is_known_zero(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.max_val.is_zero())
+    }
+
+    /// Returns true if the tracked bounds show that every limb fits in
+    /// `BIT_LEN_LIMB` bits. As `is_known_zero` it doesn't depend on witness
+    /// values.
+    pub fn has_reduced_limbs(&self) -> bool {
+        self.limbs
+            .iter()
+            .all(|limb| limb.max_val <= self.rns.max_reduced_limb)
+    }
+
     fn make_aux(&self) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         let mut max_shift = 0usize;
         let max_vals = self.max_vals();
@@ -219,6 +219,7 @@ pub struct Rns<
     pub max_most_significant_mul_quotient_limb: big_uint,
 
     /// Bit length of the maximum value allowed for residues in multiplication
+    /// including the addend of `mul_add`
     pub mul_v_bit_len: usize,
     /// Bit length of the maximum value allowed for residues in reduction
     /// circuit.
@@ -263,6 +264,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
     /// Calculates and builds a [`Rns`] with all its necessary values given
     /// the bit length used for its limbs.
+    ///
+    /// Any number of limbs above two is supported as long as limbs of
+    /// `BIT_LEN_LIMB` bits span both the wrong and the native modulus. For
+    /// example 256 bit wrong field over a 254 bit native field can be
+    /// represented with four 68 bit limbs or with three 88 bit limbs. Fewer
+    /// limbs cost fewer rows per operation but need larger lookup tables.
     pub fn construct() -> Self {
         assert!(NUMBER_OF_LIMBS > 2);
 
@@ -419,8 +426,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 })
                 .collect::<Vec<big_uint>>();
 
-            // Find intermediate maximums
+            // Find intermediate maximums. Lower limbs also carry a reduced
+            // addend as in `mul_add`
             let mut t = vec![big_uint::zero(); 2 * NUMBER_OF_LIMBS - 1];
+            for t in t.iter_mut().take(NUMBER_OF_LIMBS) {
+                *t = max_reduced_limb.clone();
+            }
             for i in 0..NUMBER_OF_LIMBS {
                 for j in 0..NUMBER_OF_LIMBS {
                     t[i + j] = &t[i + j] + &a[i] * &a[j] + &p[i] * &q[j];
@@ -814,6 +825,40 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
     }
 
+    /// Computes the witness values for `self * other + addend`
+    pub(crate) fn mul_add(
+        &self,
+        other: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        addend: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let modulus = self.rns.wrong_modulus.clone();
+        let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
+        let (quotient, result) = (self.value() * other.value() + addend.value()).div_rem(&modulus);
+        let quotient = Self::from_big(quotient, Rc::clone(&self.rns));
+        let result = Self::from_big(result, Rc::clone(&self.rns));
+
+        let l = NUMBER_OF_LIMBS;
+        let mut t: Vec<N> = addend.limbs();
+        for k in 0..l {
+            for i in 0..=k {
+                let j = k - i;
+                t[i + j] = t[i + j]
+                    + self.limb(i).0 * other.limb(j).0
+                    + negative_modulus[i] * quotient.limb(j).0;
+            }
+        }
+
+        let t = t.try_into().unwrap();
+        let residues = result.residues(&t);
+
+        ReductionWitness {
+            result,
+            intermediate: t,
+            quotient: Quotient::Long(quotient),
+            residues,
+        }
+    }
+
     // Returns division witnesses
     pub(crate) fn div(
         &self,
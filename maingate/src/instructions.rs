@@ -163,13 +163,27 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
         row: usize,
     ) -> Result<(), Error>;
 
+    /// Assigns the public input at `row` of the instance column to a new
+    /// witness that is copy constrained to it
+    fn assign_from_instance(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        row: usize,
+    ) -> Result<AssignedValue<F>, Error>;
+
     /// Constrain a witness to be equal to a fixed value. This should allow us
-    /// to move a fixed value around
+    /// to move a fixed value around. If the region context caches constants
+    /// and the value is already assigned in the region, the existing cell is
+    /// returned and no row is used
     fn assign_constant(
         &self,
         ctx: &mut RegionCtx<'_, F>,
         constant: F,
     ) -> Result<AssignedValue<F>, Error> {
+        if let Some(e) = ctx.cached_constant(constant) {
+            return Ok(e);
+        }
+
         let e = self
             .apply(
                 ctx,
@@ -178,6 +192,7 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
                 CombinationOptionCommon::OneLinerAdd.into(),
             )?
             .swap_remove(0);
+        ctx.cache_constant(constant, &e);
 
         Ok(e)
     }
@@ -378,6 +393,58 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
             .swap_remove(1))
     }
 
+    /// Assigns new value equal to `1` if all of the conditions are `1`,
+    /// equal to `0` otherwise. Conditions are combined with `and` in a binary
+    /// tree rather than folded one by one. Constant `1` is assigned if no
+    /// condition is given.
+    fn and_many(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        conditions: &[AssignedCondition<F>],
+    ) -> Result<AssignedCondition<F>, Error> {
+        if conditions.is_empty() {
+            return self.assign_constant(ctx, F::ONE);
+        }
+        let mut layer = conditions.to_vec();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [c1, c2] => self.and(ctx, c1, c2),
+                    [c] => Ok(c.clone()),
+                    _ => unreachable!(),
+                })
+                .collect::<Result<_, Error>>()?;
+        }
+        Ok(layer.pop().unwrap())
+    }
+
+    /// Assigns new value equal to `1` if any of the conditions is `1`,
+    /// equal to `0` otherwise. Conditions are combined with `or` in a binary
+    /// tree as in `and_many`. Constant `0` is assigned if no condition is
+    /// given.
+    fn or_many(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        conditions: &[AssignedCondition<F>],
+    ) -> Result<AssignedCondition<F>, Error> {
+        if conditions.is_empty() {
+            return self.assign_constant(ctx, F::ZERO);
+        }
+        let mut layer = conditions.to_vec();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [c1, c2] => self.or(ctx, c1, c2),
+                    [c] => Ok(c.clone()),
+                    _ => unreachable!(),
+                })
+                .collect::<Result<_, Error>>()?;
+        }
+        Ok(layer.pop().unwrap())
+    }
+
     /// Assigns new witness that should be equal to `a/b`. This function is
     /// unsafe because if witenss `b` is zero it cannot find a valid witness.
     fn div_unsafe(
@@ -83,6 +83,13 @@ pub struct MainGateConfig {
     pub(crate) s_constant: Column<Fixed>,
     /// column for public input
     pub instance: Column<Instance>,
+
+    #[cfg(feature = "dynamic-lookup")]
+    pub(crate) q_table: Column<Fixed>,
+    #[cfg(feature = "dynamic-lookup")]
+    pub(crate) q_lookup: Column<Fixed>,
+    #[cfg(feature = "dynamic-lookup")]
+    pub(crate) lookup_key: Column<Fixed>,
 }
 
 impl MainGateConfig {
@@ -144,6 +151,19 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
         layouter.constrain_instance(value.cell(), config.instance, row)
     }
 
+    fn assign_from_instance(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        row: usize,
+    ) -> Result<AssignedValue<F>, Error> {
+        let config = self.config();
+        // gate is not enabled so the row is only used to hold the copy
+        let value =
+            ctx.assign_advice_from_instance(|| "from instance", config.instance, row, config.a)?;
+        ctx.next();
+        Ok(value)
+    }
+
     fn assign_to_column(
         &self,
         ctx: &mut RegionCtx<'_, F>,
@@ -502,6 +522,9 @@ impl<F: PrimeField> MainGate<F> {
             ]
         });
 
+        #[cfg(feature = "dynamic-lookup")]
+        let (q_table, q_lookup, lookup_key) = Self::configure_dynamic_lookup(meta, a, b);
+
         MainGateConfig {
             a,
             b,
@@ -518,8 +541,104 @@ impl<F: PrimeField> MainGate<F> {
             s_mul_ab,
             s_mul_cd,
             instance,
+            #[cfg(feature = "dynamic-lookup")]
+            q_table,
+            #[cfg(feature = "dynamic-lookup")]
+            q_lookup,
+            #[cfg(feature = "dynamic-lookup")]
+            lookup_key,
         }
     }
+
+    /// Configures the lookup argument where the table is filled with witness
+    /// values in synthesis time.
+    ///
+    /// Table rows hold the key in the fixed `lookup_key` column and the value
+    /// at the column `b`. Lookup rows hold the witness part of the key at the
+    /// column `a` and its fixed offset in the `lookup_key` column, so the key
+    /// that is looked up is `a + lookup_key`.
+    ///
+    /// Selectors are looked up as well, otherwise every row that is not in
+    /// the table would add `(0, 0)` to it and key `0` would accept the value
+    /// `0` whatever is loaded under it.
+    #[cfg(feature = "dynamic-lookup")]
+    fn configure_dynamic_lookup(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> (Column<Fixed>, Column<Fixed>, Column<Fixed>) {
+        let q_table = meta.fixed_column();
+        let q_lookup = meta.fixed_column();
+        let lookup_key = meta.fixed_column();
+
+        meta.lookup_any("dynamic lookup", |meta| {
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let q_table = meta.query_fixed(q_table, Rotation::cur());
+            let q_lookup = meta.query_fixed(q_lookup, Rotation::cur());
+            let key = meta.query_fixed(lookup_key, Rotation::cur());
+
+            vec![
+                (q_lookup.clone(), q_table.clone()),
+                (q_lookup.clone() * (a + key.clone()), q_table.clone() * key),
+                (q_lookup * b.clone(), q_table * b),
+            ]
+        });
+
+        (q_table, q_lookup, lookup_key)
+    }
+
+    /// Adds an assigned value to the dynamic lookup table under the given key.
+    /// Keys are expected to be unique across the circuit.
+    #[cfg(feature = "dynamic-lookup")]
+    pub fn add_to_dynamic_table(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        key: F,
+        value: &AssignedValue<F>,
+    ) -> Result<(), Error> {
+        // Witness layout:
+        // | A   | B     | lookup_key | q_table |
+        // | --- | ----- | ---------- | ------- |
+        // | -   | value | key        | 1       |
+
+        ctx.assign_fixed(|| "q_table", self.config.q_table, F::ONE)?;
+        ctx.assign_fixed(|| "lookup_key", self.config.lookup_key, key)?;
+        self.apply(
+            ctx,
+            [Term::Zero, Term::assigned_to_mul(value)],
+            F::ZERO,
+            CombinationOptionCommon::OneLinerAdd.into(),
+        )?;
+        Ok(())
+    }
+
+    /// Assigns a new witness that is constrained to be the value at the key
+    /// `key + offset` of the dynamic lookup table
+    #[cfg(feature = "dynamic-lookup")]
+    pub fn dynamic_lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        key: &AssignedValue<F>,
+        offset: F,
+        value: Value<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        // Witness layout:
+        // | A   | B     | lookup_key | q_lookup |
+        // | --- | ----- | ---------- | -------- |
+        // | key | value | offset     | 1        |
+
+        ctx.assign_fixed(|| "q_lookup", self.config.q_lookup, F::ONE)?;
+        ctx.assign_fixed(|| "lookup_key", self.config.lookup_key, offset)?;
+        Ok(self
+            .apply(
+                ctx,
+                [Term::assigned_to_mul(key), Term::unassigned_to_mul(value)],
+                F::ZERO,
+                CombinationOptionCommon::OneLinerAdd.into(),
+            )?
+            .swap_remove(1))
+    }
 }
 
 #[cfg(test)]
@@ -611,6 +730,75 @@ mod tests {
         assert_eq!(prover.verify(), Ok(()));
     }
 
+    #[derive(Default)]
+    struct TestCircuitFromInstance<F: PrimeField> {
+        _marker: PhantomData<F>,
+        witness: F,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuitFromInstance<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = config.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let public_input = main_gate.assign_from_instance(ctx, 1)?;
+                    let witness = main_gate.assign_value(ctx, Value::known(self.witness))?;
+                    main_gate.assert_equal(ctx, &public_input, &witness)
+                },
+            )?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_from_instance() {
+        const K: u32 = 8;
+
+        let public_inputs = vec![vec![Fp::from(2), Fp::from(3)]];
+
+        let circuit = TestCircuitFromInstance::<Fp> {
+            witness: Fp::from(3),
+            _marker: PhantomData,
+        };
+        let prover = match MockProver::run(K, &circuit, public_inputs.clone()) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+
+        let circuit = TestCircuitFromInstance::<Fp> {
+            witness: Fp::from(2),
+            _marker: PhantomData,
+        };
+        let prover = match MockProver::run(K, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
     #[derive(Default)]
     struct TestCircuitCombination<F: PrimeField> {
         _marker: PhantomData<F>,
@@ -1357,6 +1545,197 @@ mod tests {
         assert_eq!(prover.verify(), Ok(()));
     }
 
+    #[derive(Default)]
+    struct TestCircuitAndOrMany<F: PrimeField> {
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuitAndOrMany<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // no condition
+                    let and = main_gate.and_many(ctx, &[])?;
+                    main_gate.assert_one(ctx, &and)?;
+                    let or = main_gate.or_many(ctx, &[])?;
+                    main_gate.assert_zero(ctx, &or)?;
+
+                    for n in [1, 2, 8] {
+                        let all_zero = vec![false; n];
+                        let all_one = vec![true; n];
+                        let mut first_one = all_zero.clone();
+                        first_one[0] = true;
+                        let mut last_zero = all_one.clone();
+                        last_zero[n - 1] = false;
+
+                        for bits in [all_zero, all_one, first_one, last_zero] {
+                            let conditions = bits
+                                .iter()
+                                .map(|bit| {
+                                    main_gate.assign_bit(ctx, Value::known(F::from(*bit as u64)))
+                                })
+                                .collect::<Result<Vec<AssignedCondition<F>>, Error>>()?;
+
+                            let and = main_gate.and_many(ctx, &conditions)?;
+                            if bits.iter().all(|bit| *bit) {
+                                main_gate.assert_one(ctx, &and)?;
+                            } else {
+                                main_gate.assert_zero(ctx, &and)?;
+                            }
+
+                            let or = main_gate.or_many(ctx, &conditions)?;
+                            if bits.iter().any(|bit| *bit) {
+                                main_gate.assert_one(ctx, &or)?;
+                            } else {
+                                main_gate.assert_zero(ctx, &or)?;
+                            }
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_and_or_many() {
+        const K: u32 = 8;
+
+        let circuit = TestCircuitAndOrMany::<Fp> {
+            _marker: PhantomData::<Fp>,
+        };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(K, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default)]
+    struct TestCircuitConstantCache<F: PrimeField> {
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuitConstantCache<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            for _ in 0..2 {
+                layouter.assign_region(
+                    || "region cached",
+                    |region| {
+                        let offset = 0;
+                        let ctx = &mut RegionCtx::new_with_constant_cache(region, offset);
+
+                        // first assignment in the region takes a row even if the
+                        // constant is cached in the previous region
+                        let offset = ctx.offset();
+                        let a = main_gate.assign_constant(ctx, F::from(5))?;
+                        assert_eq!(ctx.offset(), offset + 1);
+
+                        // same constant reuses the cell
+                        let offset = ctx.offset();
+                        let b = main_gate.assign_constant(ctx, F::from(5))?;
+                        assert_eq!(ctx.offset(), offset);
+                        assert!(a.cell() == b.cell());
+
+                        // a different constant gets a new cell
+                        let c = main_gate.assign_constant(ctx, F::from(6))?;
+                        assert!(a.cell() != c.cell());
+                        let d = main_gate.add_constant(ctx, &b, F::ONE)?;
+                        main_gate.assert_equal(ctx, &c, &d)?;
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            layouter.assign_region(
+                || "region uncached",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = main_gate.assign_constant(ctx, F::from(5))?;
+                    let b = main_gate.assign_constant(ctx, F::from(5))?;
+                    assert!(a.cell() != b.cell());
+                    main_gate.assert_equal(ctx, &a, &b)?;
+
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_main_gate_constant_cache() {
+        const K: u32 = 8;
+
+        let circuit = TestCircuitConstantCache::<Fp> {
+            _marker: PhantomData::<Fp>,
+        };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(K, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
     #[derive(Default)]
     struct TestCircuitDecomposition<F: PrimeField> {
         _marker: PhantomData<F>,
@@ -1590,4 +1969,73 @@ mod tests {
         };
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[cfg(feature = "dynamic-lookup")]
+    #[derive(Default)]
+    struct TestCircuitDynamicLookup<F: PrimeField> {
+        _marker: PhantomData<F>,
+        value: F,
+    }
+
+    #[cfg(feature = "dynamic-lookup")]
+    impl<F: PrimeField> Circuit<F> for TestCircuitDynamicLookup<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = config.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let v0 = main_gate.assign_value(ctx, Value::known(F::from(5)))?;
+                    let v1 = main_gate.assign_value(ctx, Value::known(F::from(7)))?;
+                    main_gate.add_to_dynamic_table(ctx, F::ZERO, &v0)?;
+                    main_gate.add_to_dynamic_table(ctx, F::ONE, &v1)?;
+
+                    // looks up the key `0`
+                    let key = main_gate.assign_value(ctx, Value::known(F::ZERO))?;
+                    main_gate.dynamic_lookup(ctx, &key, F::ZERO, Value::known(self.value))?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "dynamic-lookup")]
+    #[test]
+    fn test_main_gate_dynamic_lookup() {
+        use halo2wrong::utils::{mock_prover_verify, mock_prover_verify_fails};
+
+        let circuit = |value: u64| TestCircuitDynamicLookup::<Fp> {
+            _marker: PhantomData,
+            value: Fp::from(value),
+        };
+        mock_prover_verify(&circuit(5), vec![vec![]]);
+        // value of another key
+        mock_prover_verify_fails(&circuit(7), vec![vec![]]);
+        // forged zero must not match the rows out of the table
+        mock_prover_verify_fails(&circuit(0), vec![vec![]]);
+    }
 }